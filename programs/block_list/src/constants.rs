@@ -4,6 +4,18 @@ use anchor_lang::prelude::*;
 pub const SEED: &str = "anchor";
 
 
-/// Maximum addresses per block list based on realloc limits
+/// Maximum addresses that can be allocated for a block list in a single instruction, either when it is
+/// created or grown. Accounts can only be created or grown by 10240 bytes per instruction, which must also
+/// cover the header when the block list is created
 #[constant]
-pub const MAX_ADDRESSES_PER_LIST: u64 = 318;
\ No newline at end of file
+pub const MAX_ADDRESSES_PER_INSTRUCTION: u64 = 127;
+
+/// Maximum addresses a block list can grow to based on the maximum account size
+#[constant]
+pub const MAX_BLOCK_LIST_ADDRESSES: u64 = 131_071;
+
+/// Address expected to initialize the management account, set at compile time through the
+/// `EXPECTED_ADMIN` environment variable for deployments without an upgrade authority.
+///
+/// When unset, the management account can only be initialized by the upgrade authority.
+pub const EXPECTED_ADMIN: Option<&str> = option_env!("EXPECTED_ADMIN");
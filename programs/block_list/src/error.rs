@@ -12,4 +12,38 @@ pub enum ErrorCode {
     Denied,
     #[msg("Provided account meta list account is invalid")]
     InvalidExtraAccountMetasList,
+    #[msg("Too many addresses added to the block list in a single instruction")]
+    BlockListGrowthTooLarge,
+    #[msg("Block list does not belong to the mint")]
+    InvalidBlockList,
+    #[msg("Block list number does not match the next block list number for the mint")]
+    InvalidListNumber,
+    #[msg("Invalid list type provided")]
+    InvalidListType,
+    #[msg("Authority is not allowed")]
+    NotAllowed,
+    #[msg("Provided mint config account is invalid")]
+    InvalidMintConfig,
+    #[msg("Provided program data account is invalid")]
+    InvalidProgramData,
+    #[msg("Expected admin set at compile time is not a valid address")]
+    InvalidExpectedAdmin,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Invalid roles")]
+    InvalidRoles,
+    #[msg("Transfers of the mint are paused")]
+    Paused,
+    #[msg("Expiry must be 0 or in the future")]
+    InvalidExpiry,
+    #[msg("Invalid block reason")]
+    InvalidBlockReason,
+    #[msg("Mint is not a Token-2022 mint with a transfer hook invoking this program")]
+    InvalidMint,
+    #[msg("Signer is not the transfer hook authority of the mint")]
+    InvalidTransferHookAuthority,
+    #[msg("Transfer hook was not invoked by a Token-2022 transfer")]
+    NotTransferring,
+    #[msg("Initial capacity exceeds the maximum number of addresses per list")]
+    InvalidInitialCapacity,
 }
@@ -0,0 +1,125 @@
+use {
+    crate::{
+        block_list::{BlockReason, ListType},
+        mint_config::ListMode,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub roles: u8,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub holder: Pubkey,
+    pub roles: u8,
+}
+
+#[event]
+pub struct MintInitialized {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub config_authority: Pubkey,
+}
+
+#[event]
+pub struct ListModeUpdated {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub list_mode: ListMode,
+}
+
+#[event]
+pub struct MintPaused {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct MintUnpaused {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct BlockListCreated {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub block_list: Pubkey,
+    pub list_number: u64,
+    pub list_type: ListType,
+}
+
+#[event]
+pub struct BlockListClosed {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub block_list: Pubkey,
+}
+
+#[event]
+pub struct AddressesAdded {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub block_list: Pubkey,
+    pub addresses: Vec<Pubkey>,
+    pub expires_at: i64,
+    pub reason: BlockReason,
+    pub case_reference: [u8; 32],
+}
+
+#[event]
+pub struct AddressesRemoved {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub block_list: Pubkey,
+    pub addresses: Vec<Pubkey>,
+}
+
+#[event]
+pub struct ExpiredEntriesPruned {
+    pub mint: Pubkey,
+    pub block_list: Pubkey,
+    pub num_pruned: u64,
+}
+
+#[event]
+pub struct AddressBlocked {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub address: Pubkey,
+    pub reason: BlockReason,
+    pub case_reference: [u8; 32],
+}
+
+#[event]
+pub struct AddressUnblocked {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub address: Pubkey,
+}
@@ -0,0 +1,68 @@
+use {
+    crate::{
+        error::ErrorCode, events::BlockListClosed, mint_config::MintConfig, role::Role,
+        state::block_list::BlockList,
+        utils::remove_extra_account_meta,
+    },
+    anchor_lang::prelude::*,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
+};
+
+#[derive(Accounts)]
+pub struct CloseBlockList<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"mint_config", mint.key().as_ref()],
+        bump,
+        constraint = mint_config.is_authorized(authority.key(), authority_role.as_deref(), Role::ADMIN) @ ErrorCode::Unauthorized
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: validated through account metas
+    pub mint: AccountInfo<'info>,
+    #[account(
+        mut,
+        close = authority,
+        constraint = block_list.load()?.mint == mint.key() @ ErrorCode::InvalidBlockList
+    )]
+    pub block_list: AccountLoader<'info, BlockList>,
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+}
+
+impl CloseBlockList<'_> {
+    /// Removes the block list from the accounts passed into the transfer hook and closes it,
+    /// refunding the rent of both accounts to the authority
+    pub fn handler(ctx: Context<CloseBlockList>) -> Result<()> {
+        let removed = remove_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.block_list.key(), false, false)?,
+        )?;
+        require!(removed, ErrorCode::InvalidBlockList);
+
+        let mint_config = &mut ctx.accounts.mint_config;
+        mint_config.num_active_block_lists = mint_config
+            .num_active_block_lists
+            .checked_sub(1)
+            .ok_or(ErrorCode::InvalidMintConfig)?;
+        emit!(BlockListClosed {
+            authority: ctx.accounts.authority.key(),
+            mint: mint_config.mint,
+            block_list: ctx.accounts.block_list.key(),
+        });
+        Ok(())
+    }
+}
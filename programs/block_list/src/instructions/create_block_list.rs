@@ -1,42 +1,48 @@
 use {
     crate::{
-        error::ErrorCode, management::Management, state::block_list::BlockList,
-        MAX_ADDRESSES_PER_LIST,
+        error::ErrorCode,
+        events::BlockListCreated,
+        mint_config::MintConfig,
+        role::Role,
+        state::block_list::{BlockList, ListType},
+        utils::append_extra_account_meta,
+        MAX_ADDRESSES_PER_INSTRUCTION,
     },
-    anchor_lang::{
-        prelude::*,
-        solana_program::{program::invoke, system_instruction},
-    },
-    spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList},
-    spl_transfer_hook_interface::instruction::{
-        ExecuteInstruction, UpdateExtraAccountMetaListInstruction,
-    },
-    spl_type_length_value::state::TlvStateBorrowed,
+    anchor_lang::prelude::*,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
 };
 
 #[derive(Accounts)]
-#[instruction(list_number: u64)]
+#[instruction(list_number: u64, initial_capacity: u64, list_type: ListType)]
 pub struct CreateBlockList<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
     #[account(
         mut,
-        seeds = [b"management"],
+        seeds = [b"mint_config", mint.key().as_ref()],
         bump,
+        constraint = mint_config.num_block_lists == list_number @ ErrorCode::InvalidListNumber,
     )]
-    pub management: Account<'info, Management>,
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
     /// CHECK: validated through account metas
     pub mint: AccountInfo<'info>,
     #[account(
         init,
-        seeds = [b"block_list", list_number.to_le_bytes().as_ref()],
+        seeds = [b"block_list", mint.key().as_ref(), list_number.to_le_bytes().as_ref()],
         payer = authority,
-        // max relloac space is 10240 bytes which is enough for 320 accounts
-        // however the discriminator is 8 bytes, so we can only support 319 accounts
-        space = BlockList::space(MAX_ADDRESSES_PER_LIST as usize),
+        // max account creation space is 10240 bytes, so the initial capacity
+        // can be at most MAX_ADDRESSES_PER_INSTRUCTION, the list is grown as addresses are added.
+        // the space is evaluated before the account is created, rejecting larger capacities early
+        space = CreateBlockList::space(initial_capacity)?,
         bump,
     )]
-    pub block_list: Account<'info, BlockList>,
+    pub block_list: AccountLoader<'info, BlockList>,
     /// CHECK: ExtraAccountMetaList Account, must use these seeds
     #[account(
         mut,
@@ -49,73 +55,59 @@ pub struct CreateBlockList<'info> {
 }
 
 impl CreateBlockList<'_> {
-    pub fn handler(ctx: Context<CreateBlockList>, _list_number: u64) -> Result<()> {
+    /// Returns the space of a block list with room for `initial_capacity` addresses
+    fn space(initial_capacity: u64) -> Result<usize> {
+        require!(
+            initial_capacity <= MAX_ADDRESSES_PER_INSTRUCTION,
+            ErrorCode::InvalidInitialCapacity
+        );
+        Ok(BlockList::space(initial_capacity as usize))
+    }
+    pub fn handler(
+        ctx: Context<CreateBlockList>,
+        _list_number: u64,
+        _initial_capacity: u64,
+        list_type: ListType,
+    ) -> Result<()> {
         Self::validations(&ctx)?;
         let new_list_number = {
-            let management = &mut ctx.accounts.management;
-            management.increment_and_get_new_list_number()
-        };
-        let block_list = &mut ctx.accounts.block_list;
-        block_list.denied_addresses = Vec::new();
-        block_list.block_list_number = new_list_number;
-
-        // get current accoutns
-        let mut account_metas: Vec<ExtraAccountMeta> = {
-            let data = ctx.accounts.extra_account_meta_list.try_borrow_data()?;
-            let tlv_state = TlvStateBorrowed::unpack(&data)?;
-            let extra_accounts =
-                ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)?;
-            extra_accounts.data().to_vec()
+            let mint_config = &mut ctx.accounts.mint_config;
+            mint_config.increment_and_get_new_list_number()
         };
-        // add new account
-        account_metas.push(ExtraAccountMeta::new_with_pubkey(
-            &ctx.accounts.block_list.key(),
-            false,
-            false,
-        )?)
-        ;
-        let account_size = ctx.accounts.extra_account_meta_list.data_len();
-        // calculate account size
-        let data_to_add = ExtraAccountMetaList::size_of(account_metas.len())? as u64;
-        let new_account_size = account_size + data_to_add as usize;
-        // Current balance of the account
-        let current_balance = ctx.accounts.extra_account_meta_list.lamports();
-        // calculate minimum required lamports
-        let minimum_balance = Rent::get()?.minimum_balance(new_account_size as usize);
-        // If we need more lamports for rent exemption
-        if minimum_balance > current_balance {
-            let lamports_to_add = minimum_balance - current_balance;
-            invoke(
-                &system_instruction::transfer(
-                    ctx.accounts.authority.key,
-                    ctx.accounts.extra_account_meta_list.key,
-                    lamports_to_add,
-                ),
-                &[
-                    ctx.accounts.authority.to_account_info(),
-                    ctx.accounts.extra_account_meta_list.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-            )?;
+        {
+            let mut block_list = ctx.accounts.block_list.load_init()?;
+            block_list.block_list_number = new_list_number;
+            block_list.num_addresses = 0;
+            block_list.mint = ctx.accounts.mint.key();
+            block_list.list_type = list_type as u8;
         }
 
-        // Reallocate the account to the new size
-        ctx.accounts
-            .extra_account_meta_list
-            .realloc(new_account_size as usize, false)?;
-
-        ExtraAccountMetaList::update::<ExecuteInstruction>(
-            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
-            &account_metas,
+        // add the block list to the accounts passed into the transfer hook
+        append_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.block_list.key(), false, false)?,
         )?;
 
+        emit!(BlockListCreated {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            block_list: ctx.accounts.block_list.key(),
+            list_number: new_list_number,
+            list_type,
+        });
         Ok(())
     }
     fn validations(ctx: &Context<CreateBlockList>) -> Result<()> {
         require!(
             ctx.accounts
-                .management
-                .is_authorized(ctx.accounts.authority.key()),
+                .mint_config
+                .is_authorized(
+                    ctx.accounts.authority.key(),
+                    ctx.accounts.authority_role.as_deref(),
+                    Role::ADMIN
+                ),
             ErrorCode::Unauthorized
         );
         Ok(())
@@ -1,4 +1,8 @@
-use {crate::{management::Management, state::block_list::BlockList, MAX_ADDRESSES_PER_LIST}, anchor_lang::prelude::*};
+use {
+    crate::{error::ErrorCode, management::Management, program::BlockLists, EXPECTED_ADMIN},
+    anchor_lang::prelude::*,
+    std::str::FromStr,
+};
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -9,22 +13,54 @@ pub struct Initialize<'info> {
         init,
         seeds = [b"management"],
         payer = authority,
-        // max relloac space is 10240 bytes which is enough for 320 accounts
-        // however the discriminator is 8 bytes, so we can only support 319 accounts
         space = Management::space(),
         bump,
     )]
     pub management: Account<'info, Management>,
 
+    pub program: Program<'info, BlockLists>,
+    /// program data account of this program, storing the upgrade authority.
+    ///
+    /// may be omitted if the program was built with an expected admin
+    pub program_data: Option<Account<'info, ProgramData>>,
+
     pub system_program: Program<'info, System>,
 }
 
 impl Initialize<'_> {
     pub fn handler(ctx: Context<Initialize>) -> Result<()> {
+        Self::validations(&ctx)?;
+
         let management = &mut ctx.accounts.management;
 
         management.authority = ctx.accounts.authority.key();
-        management.num_block_lists = 0;
+        management.pending_authority = None;
+        Ok(())
+    }
+    /// Ensures the management account can only be initialized by the expected admin if one was set
+    /// at compile time, and by the upgrade authority of the program otherwise
+    fn validations(ctx: &Context<Initialize>) -> Result<()> {
+        let authority = ctx.accounts.authority.key();
+        if let Some(expected_admin) = EXPECTED_ADMIN {
+            let expected_admin =
+                Pubkey::from_str(expected_admin).map_err(|_| ErrorCode::InvalidExpectedAdmin)?;
+            require_keys_eq!(authority, expected_admin, ErrorCode::Unauthorized);
+            return Ok(());
+        }
+
+        let program_data = ctx
+            .accounts
+            .program_data
+            .as_ref()
+            .ok_or(ErrorCode::InvalidProgramData)?;
+        require!(
+            ctx.accounts.program.programdata_address()? == Some(program_data.key()),
+            ErrorCode::InvalidProgramData
+        );
+        require!(
+            program_data.upgrade_authority_address == Some(authority),
+            ErrorCode::Unauthorized
+        );
         Ok(())
     }
 }
@@ -1,16 +1,19 @@
 use {
-    crate::{block_list::BlockList, error::ErrorCode, management::Management},
+    crate::{
+        error::ErrorCode,
+        events::MintInitialized,
+        management::Management,
+        mint_config::{ListMode, MintConfig},
+        utils::validate_transfer_hook_mint,
+    },
     anchor_lang::{
-        prelude::*, solana_program::{program::invoke_signed, system_instruction}, system_program::{create_account, CreateAccount}
+        prelude::*,
+        system_program::{create_account, CreateAccount},
     },
-    anchor_spl::{
-        associated_token::AssociatedToken,
-        token_2022::{
-            spl_token_2022::{extension::StateWithExtensions, state::Mint},
-            Token2022,
-        },
+    anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022},
+    spl_tlv_account_resolution::{
+        account::ExtraAccountMeta, seeds::Seed, state::ExtraAccountMetaList,
     },
-    spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList},
     spl_transfer_hook_interface::instruction::ExecuteInstruction,
 };
 
@@ -22,6 +25,17 @@ pub struct InitializeExtraAccountMetaList<'info> {
         constraint = management.authority == authority.key() @ ErrorCode::Unauthorized
     )]
     pub management: Account<'info, Management>,
+    /// The address which will manage the block lists of the mint, which must be the
+    /// transfer hook authority of the mint
+    pub config_authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"mint_config", mint.key().as_ref()],
+        payer = authority,
+        space = MintConfig::space(),
+        bump,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
 
     /// CHECK: ExtraAccountMetaList Account, must use these seeds
     #[account(
@@ -39,6 +53,17 @@ pub struct InitializeExtraAccountMetaList<'info> {
 
 impl InitializeExtraAccountMetaList<'_> {
     pub fn handler(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        Self::validations(&ctx)?;
+        {
+            let mint_config = &mut ctx.accounts.mint_config;
+            mint_config.mint = ctx.accounts.mint.key();
+            mint_config.authority = ctx.accounts.config_authority.key();
+            mint_config.num_block_lists = 0;
+            mint_config.num_active_block_lists = 0;
+            mint_config.list_mode = ListMode::DenyList;
+            mint_config.paused = false;
+        }
+
         // index 0-3 are the accounts required for token transfer (source, mint, destination, owner)
         // index 4 is address of ExtraAccountMetaList account
         let account_metas = vec![
@@ -50,8 +75,22 @@ impl InitializeExtraAccountMetaList<'_> {
                 false,
                 false,
             )?,
-            // index 7
-            //ExtraAccountMeta::new_with_pubkey(&ctx.accounts.block_list.key(), false, false)?,
+            // index 7, block marker of the source token account owner
+            Self::block_marker_meta(Seed::AccountData {
+                account_index: 0,
+                data_index: 32,
+                length: 32,
+            })?,
+            // index 8, block marker of the destination token account owner
+            Self::block_marker_meta(Seed::AccountData {
+                account_index: 2,
+                data_index: 32,
+                length: 32,
+            })?,
+            // index 9, block marker of the owner, which may be a delegated signer
+            Self::block_marker_meta(Seed::AccountKey { index: 3 })?,
+            // index 10, mint config
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.mint_config.key(), false, false)?,
         ];
 
         // calculate account size
@@ -62,7 +101,7 @@ impl InitializeExtraAccountMetaList<'_> {
         let mint = ctx.accounts.mint.key();
         let signer_seeds: &[&[&[u8]]] = &[&[
             b"extra-account-metas",
-            &mint.as_ref(),
+            mint.as_ref(),
             &[ctx.bumps.extra_account_meta_list],
         ]];
 
@@ -86,6 +125,29 @@ impl InitializeExtraAccountMetaList<'_> {
             &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
             &account_metas,
         )?;
+        emit!(MintInitialized {
+            authority: ctx.accounts.authority.key(),
+            mint,
+            config_authority: ctx.accounts.config_authority.key(),
+        });
         Ok(())
     }
+    /// Returns the account meta resolving to the block marker of the address given by `address_seed`
+    fn block_marker_meta(address_seed: Seed) -> Result<ExtraAccountMeta> {
+        Ok(ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: b"blocked".to_vec(),
+                },
+                // index 1, the mint
+                Seed::AccountKey { index: 1 },
+                address_seed,
+            ],
+            false,
+            false,
+        )?)
+    }
+    fn validations(ctx: &Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        validate_transfer_hook_mint(&ctx.accounts.mint, ctx.accounts.config_authority.key)
+    }
 }
@@ -1,47 +1,147 @@
 use {
-    crate::{error::ErrorCode, management::Management, state::block_list::BlockList, MAX_ADDRESSES_PER_LIST},
-    anchor_lang::prelude::*,
-    std::collections::HashSet,
+    crate::{
+        error::ErrorCode,
+        events::{AddressesAdded, AddressesRemoved},
+        mint_config::MintConfig,
+        role::Role,
+        state::block_list::{BlockList, BlockReason},
+        MAX_ADDRESSES_PER_INSTRUCTION, MAX_BLOCK_LIST_ADDRESSES,
+    },
+    anchor_lang::{
+        prelude::*,
+        solana_program::{program::invoke, system_instruction},
+    },
 };
 
 #[derive(Accounts)]
 pub struct ManageBlockList<'info> {
+    #[account(mut)]
     pub authority: Signer<'info>,
-    pub management: Account<'info, Management>,
+    #[account(
+        seeds = [b"mint_config", mint_config.mint.as_ref()],
+        bump,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
     #[account(
         mut,
-        constraint = management.authority == authority.key() @ ErrorCode::Unauthorized
+        constraint = block_list.load()?.mint == mint_config.mint @ ErrorCode::InvalidBlockList
     )]
-    pub block_list: Account<'info, BlockList>,
+    pub block_list: AccountLoader<'info, BlockList>,
+    pub system_program: Program<'info, System>,
 }
 
 impl ManageBlockList<'_> {
-    pub fn add_handler(ctx: Context<ManageBlockList>, addresses: Vec<Pubkey>) -> Result<()> {
+    /// Adds `addresses` to the block list for `reason` and `case_reference`, their entries expire
+    /// at the unix timestamp `expires_at` unless it is 0
+    pub fn add_handler(
+        ctx: Context<ManageBlockList>,
+        addresses: Vec<Pubkey>,
+        expires_at: i64,
+        reason: BlockReason,
+        case_reference: [u8; 32],
+    ) -> Result<()> {
+        Self::validations(&ctx, Role::LIST_EDITOR)?;
         require!(
-            addresses.len() + ctx.accounts.block_list.denied_addresses.len() <= MAX_ADDRESSES_PER_LIST as usize,            ErrorCode::BlockListFull
+            expires_at == 0 || expires_at > Clock::get()?.unix_timestamp,
+            ErrorCode::InvalidExpiry
         );
+        let block_list = ctx.accounts.block_list.to_account_info();
+        let (new_entries, num_addresses) = {
+            let mut data = block_list.try_borrow_mut_data()?;
+            let new_entries = BlockList::new_entries(
+                &mut data,
+                addresses.clone(),
+                expires_at,
+                reason,
+                case_reference,
+            );
+            let num_addresses = BlockList::header(&data).num_addresses as usize + new_entries.len();
+            (new_entries, num_addresses)
+        };
 
-        let mut current_addresses: HashSet<_> = ctx
-            .accounts
-            .block_list
-            .denied_addresses
-            .iter()
-            .cloned()
-            .collect();
+        require!(
+            num_addresses <= MAX_BLOCK_LIST_ADDRESSES as usize,
+            ErrorCode::BlockListFull
+        );
+        Self::grow(&ctx, num_addresses)?;
 
-        for address in addresses {
-            current_addresses.insert(address);
+        BlockList::insert_sorted(&mut block_list.try_borrow_mut_data()?, &new_entries);
+        emit!(AddressesAdded {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+            block_list: block_list.key(),
+            addresses,
+            expires_at,
+            reason,
+            case_reference,
+        });
+        Ok(())
+    }
+    pub fn remove_handler(ctx: Context<ManageBlockList>, addresses: Vec<Pubkey>) -> Result<()> {
+        Self::validations(&ctx, Role::ADMIN)?;
+        let block_list = ctx.accounts.block_list.to_account_info();
+        BlockList::remove_sorted(&mut block_list.try_borrow_mut_data()?, addresses.clone());
+        emit!(AddressesRemoved {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+            block_list: block_list.key(),
+            addresses,
+        });
+        Ok(())
+    }
+    /// Reallocates the block list if it does not have room for `num_addresses`,
+    /// topping up the rent from the authority
+    fn grow(ctx: &Context<ManageBlockList>, num_addresses: usize) -> Result<()> {
+        let block_list = ctx.accounts.block_list.to_account_info();
+        let account_size = block_list.data_len();
+        if BlockList::capacity(account_size) >= num_addresses {
+            return Ok(());
+        }
+        require!(
+            num_addresses - BlockList::capacity(account_size) <= MAX_ADDRESSES_PER_INSTRUCTION as usize,
+            ErrorCode::BlockListGrowthTooLarge
+        );
+        let new_account_size = BlockList::space(num_addresses);
+        // Current balance of the account
+        let current_balance = block_list.lamports();
+        // calculate minimum required lamports
+        let minimum_balance = Rent::get()?.minimum_balance(new_account_size);
+        // If we need more lamports for rent exemption
+        if minimum_balance > current_balance {
+            let lamports_to_add = minimum_balance - current_balance;
+            invoke(
+                &system_instruction::transfer(
+                    ctx.accounts.authority.key,
+                    block_list.key,
+                    lamports_to_add,
+                ),
+                &[
+                    ctx.accounts.authority.to_account_info(),
+                    block_list.clone(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
         }
 
-        ctx.accounts.block_list.denied_addresses = current_addresses.into_iter().collect();
+        // Reallocate the account to the new size
+        block_list.realloc(new_account_size, false)?;
         Ok(())
     }
-    pub fn remove_handler(ctx: Context<ManageBlockList>, addresses: Vec<Pubkey>) -> Result<()> {
-        let remove_set: HashSet<_> = addresses.into_iter().collect();
-        ctx.accounts
-            .block_list
-            .denied_addresses
-            .retain(|addr| !remove_set.contains(addr));
+    fn validations(ctx: &Context<ManageBlockList>, required_role: u8) -> Result<()> {
+        require!(
+            ctx.accounts.mint_config.is_authorized(
+                ctx.accounts.authority.key(),
+                ctx.accounts.authority_role.as_deref(),
+                required_role
+            ),
+            ErrorCode::Unauthorized
+        );
         Ok(())
     }
 }
@@ -0,0 +1,104 @@
+use {
+    crate::{
+        error::ErrorCode,
+        events::{AddressBlocked, AddressUnblocked},
+        mint_config::MintConfig,
+        role::Role,
+        state::{block_list::BlockReason, block_marker::BlockMarker},
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct BlockAddress<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"mint_config", mint.key().as_ref()],
+        bump,
+        constraint = mint_config.is_authorized(authority.key(), authority_role.as_deref(), Role::LIST_EDITOR) @ ErrorCode::Unauthorized
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: mint of the token the address is blocked for
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        init,
+        seeds = [b"blocked", mint.key().as_ref(), address.as_ref()],
+        payer = authority,
+        space = BlockMarker::space(),
+        bump,
+    )]
+    pub block_marker: Account<'info, BlockMarker>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(address: Pubkey)]
+pub struct UnblockAddress<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"mint_config", mint.key().as_ref()],
+        bump,
+        constraint = mint_config.is_authorized(authority.key(), authority_role.as_deref(), Role::ADMIN) @ ErrorCode::Unauthorized
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: mint of the token the address is blocked for
+    pub mint: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"blocked", mint.key().as_ref(), address.as_ref()],
+        bump,
+    )]
+    pub block_marker: Account<'info, BlockMarker>,
+}
+
+impl BlockAddress<'_> {
+    /// Creates the block marker for `address`, denying any transfers it is involved in
+    pub fn handler(
+        ctx: Context<BlockAddress>,
+        address: Pubkey,
+        reason: BlockReason,
+        case_reference: [u8; 32],
+    ) -> Result<()> {
+        let block_marker = &mut ctx.accounts.block_marker;
+        block_marker.mint = ctx.accounts.mint.key();
+        block_marker.address = address;
+        block_marker.reason = reason;
+        block_marker.case_reference = case_reference;
+        emit!(AddressBlocked {
+            authority: ctx.accounts.authority.key(),
+            mint: block_marker.mint,
+            address,
+            reason,
+            case_reference,
+        });
+        Ok(())
+    }
+}
+
+impl UnblockAddress<'_> {
+    /// Closes the block marker for `address`, refunding the rent to the authority
+    pub fn handler(ctx: Context<UnblockAddress>, address: Pubkey) -> Result<()> {
+        emit!(AddressUnblocked {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            address,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use {
+    crate::{
+        error::ErrorCode,
+        events::{RoleGranted, RoleRevoked},
+        mint_config::MintConfig,
+        role::Role,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"mint_config", mint_config.mint.as_ref()],
+        bump,
+        constraint = mint_config.is_authorized(authority.key(), authority_role.as_deref(), Role::ADMIN) @ ErrorCode::Unauthorized
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    #[account(
+        init,
+        seeds = [b"role", mint_config.mint.as_ref(), holder.as_ref()],
+        payer = authority,
+        space = Role::space(),
+        bump,
+    )]
+    pub role: Account<'info, Role>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"mint_config", mint_config.mint.as_ref()],
+        bump,
+        constraint = mint_config.is_authorized(authority.key(), authority_role.as_deref(), Role::ADMIN) @ ErrorCode::Unauthorized
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"role", mint_config.mint.as_ref(), holder.as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, Role>,
+}
+
+impl GrantRole<'_> {
+    /// Grants `roles` for the mint to `holder`, roles are changed by revoking and granting them again
+    pub fn handler(ctx: Context<GrantRole>, holder: Pubkey, roles: u8) -> Result<()> {
+        require!(Role::is_valid(roles), ErrorCode::InvalidRoles);
+        let role = &mut ctx.accounts.role;
+        role.mint = ctx.accounts.mint_config.mint;
+        role.holder = holder;
+        role.roles = roles;
+        emit!(RoleGranted {
+            authority: ctx.accounts.authority.key(),
+            mint: role.mint,
+            holder,
+            roles,
+        });
+        Ok(())
+    }
+}
+
+impl RevokeRole<'_> {
+    /// Closes the role account of `holder`, refunding the rent to the authority
+    pub fn handler(ctx: Context<RevokeRole>, holder: Pubkey) -> Result<()> {
+        emit!(RoleRevoked {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+            holder,
+            roles: ctx.accounts.role.roles,
+        });
+        Ok(())
+    }
+}
@@ -1,11 +1,23 @@
 pub mod initialize;
 pub mod initialize_extra_account_meta_list;
 pub mod manage_block_list;
+pub mod manage_block_marker;
+pub mod manage_role;
+pub mod prune_block_list;
+pub mod transfer_authority;
 pub mod transfer_hook;
 pub mod create_block_list;
+pub mod close_block_list;
+pub mod update_mint_config;
 
 pub use initialize::*;
 pub use initialize_extra_account_meta_list::*;
 pub use manage_block_list::*;
+pub use manage_block_marker::*;
+pub use manage_role::*;
+pub use prune_block_list::*;
+pub use transfer_authority::*;
 pub use transfer_hook::*;
 pub use create_block_list::*;
+pub use close_block_list::*;
+pub use update_mint_config::*;
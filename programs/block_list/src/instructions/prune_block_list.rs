@@ -0,0 +1,27 @@
+use {
+    crate::{events::ExpiredEntriesPruned, state::block_list::BlockList},
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct PruneBlockList<'info> {
+    #[account(mut)]
+    pub block_list: AccountLoader<'info, BlockList>,
+}
+
+impl PruneBlockList<'_> {
+    /// Removes the expired entries from the block list, freeing their capacity for new entries.
+    ///
+    /// Expired entries are already ignored by the transfer hook, so this can be run by anyone.
+    pub fn handler(ctx: Context<PruneBlockList>) -> Result<()> {
+        let block_list = ctx.accounts.block_list.to_account_info();
+        let mut data = block_list.try_borrow_mut_data()?;
+        let num_pruned = BlockList::prune_expired(&mut data, Clock::get()?.unix_timestamp);
+        emit!(ExpiredEntriesPruned {
+            mint: BlockList::header(&data).mint,
+            block_list: block_list.key(),
+            num_pruned: num_pruned as u64,
+        });
+        Ok(())
+    }
+}
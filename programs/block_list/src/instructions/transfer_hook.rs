@@ -1,6 +1,12 @@
 use {
-    crate::{block_list::BlockList, error::ErrorCode},
-    anchor_lang::prelude::*,
+    crate::{
+        block_list::{BlockList, BlockReason, ListType},
+        block_marker::BlockMarker,
+        error::ErrorCode,
+        mint_config::{ListMode, MintConfig},
+        utils::assert_is_transferring,
+    },
+    anchor_lang::{prelude::*, Discriminator},
     anchor_spl::{
         associated_token::AssociatedToken,
         token_2022::{
@@ -10,6 +16,17 @@ use {
     },
 };
 
+/// Names of the owners checked by the transfer hook, in the order they are checked
+const PARTIES: [&str; 3] = ["signer", "source owner", "destination owner"];
+
+/// The first block list entry or block marker which matched one of the owners
+struct Denial {
+    /// Index of the matched owner in `PARTIES`
+    party: usize,
+    reason: BlockReason,
+    case_reference: [u8; 32],
+}
+
 #[derive(Accounts)]
 pub struct TransferHook<'info> {
     /// CHECK: validated by token2022 program
@@ -40,7 +57,8 @@ impl TransferHook<'_> {
             ctx.accounts.extra_account_meta_list.owner.eq(&crate::ID),
             ErrorCode::InvalidExtraAccountMetasList
         );
-        if ctx.remaining_accounts.len() == 0 {
+        assert_is_transferring(&ctx.accounts.source_token)?;
+        if ctx.remaining_accounts.is_empty() {
             panic!("unexpected condition")
         }
         // build list of owners to check which will be evaluated against all block lists
@@ -59,17 +77,82 @@ impl TransferHook<'_> {
             owners_to_check.push(receiving_account.base.owner);
         }
 
-        // evaluate all block lists to see if any of the owners are denied
+        // evaluate all block markers and lists, recording whether any of the owners are denied
+        // and which owners are allowed, as the mode they are evaluated with may be loaded after them
+        let mut list_mode: Option<ListMode> = None;
+        let mut denial: Option<Denial> = None;
+        let mut allowed = vec![false; owners_to_check.len()];
+        let now = Clock::get()?.unix_timestamp;
         for remaining_account in ctx.remaining_accounts.iter() {
-            msg!("checking block list");
-            let block_list: Account<BlockList> = Account::try_from(remaining_account)?;
-            for owner_to_check in &owners_to_check {
+            // block markers for addresses which are not blocked resolve to uninitialized accounts
+            if !remaining_account.owner.eq(&crate::ID) {
+                continue;
+            }
+            let mut discriminator: [u8; 8] = [0u8; 8];
+            discriminator.copy_from_slice(&remaining_account.try_borrow_data()?[0..8]);
+            if MintConfig::discriminator().eq(&discriminator) {
+                let mint_config: Account<MintConfig> = Account::try_from(remaining_account)?;
+                require!(
+                    mint_config.mint.eq(ctx.accounts.mint.key),
+                    ErrorCode::InvalidMintConfig
+                );
+                require!(!mint_config.paused, ErrorCode::Paused);
+                list_mode = Some(mint_config.list_mode);
+            } else if BlockMarker::discriminator().eq(&discriminator) {
+                let block_marker: Account<BlockMarker> = Account::try_from(remaining_account)?;
+                let party = owners_to_check
+                    .iter()
+                    .position(|owner_to_check| owner_to_check.eq(&block_marker.address));
+                if let Some(party) = party.filter(|_| block_marker.mint.eq(ctx.accounts.mint.key)) {
+                    denial.get_or_insert(Denial {
+                        party,
+                        reason: block_marker.reason,
+                        case_reference: block_marker.case_reference,
+                    });
+                }
+            } else {
+                // validates the owner and discriminator of the block list
+                AccountLoader::<BlockList>::try_from(remaining_account)?;
+                let data = remaining_account.try_borrow_data()?;
+                let header = BlockList::header(&data);
                 require!(
-                    !block_list.transfer_denied(*owner_to_check),
-                    ErrorCode::Denied
+                    header.mint.eq(ctx.accounts.mint.key),
+                    ErrorCode::InvalidBlockList
+                );
+                let list_type = header.list_type()?;
+                for (index, owner_to_check) in owners_to_check.iter().enumerate() {
+                    if let Some(entry) = BlockList::get(&data, owner_to_check, now) {
+                        match list_type {
+                            ListType::Deny => {
+                                denial.get_or_insert(Denial {
+                                    party: index,
+                                    reason: entry.reason()?,
+                                    case_reference: entry.case_reference,
+                                });
+                            }
+                            ListType::Allow => allowed[index] = true,
+                        }
+                    }
+                }
+            }
+        }
+
+        let list_mode = list_mode.ok_or(ErrorCode::InvalidMintConfig)?;
+        if list_mode.deny_lists_enabled() {
+            if let Some(denial) = denial {
+                msg!(
+                    "{} {} is blocked for {:?}, case reference: {}",
+                    PARTIES[denial.party],
+                    owners_to_check[denial.party],
+                    denial.reason,
+                    String::from_utf8_lossy(&denial.case_reference).trim_end_matches('\0')
                 );
+                return Err(ErrorCode::Denied.into());
             }
         }
+        if list_mode.allow_lists_enabled() {
+            require!(allowed.iter().all(|allowed| *allowed), ErrorCode::NotAllowed);
+        }
         Ok(())
     }
 }
@@ -0,0 +1,71 @@
+use {
+    crate::{
+        error::ErrorCode,
+        events::{ListModeUpdated, MintPaused, MintUnpaused},
+        mint_config::{ListMode, MintConfig},
+        role::Role,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateMintConfig<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"mint_config", mint_config.mint.as_ref()],
+        bump,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    /// Role of the authority, not required if the authority is the mint config authority
+    #[account(
+        seeds = [b"role", mint_config.mint.as_ref(), authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+}
+
+impl UpdateMintConfig<'_> {
+    /// Sets which lists are evaluated by the transfer hook
+    pub fn set_list_mode_handler(ctx: Context<UpdateMintConfig>, list_mode: ListMode) -> Result<()> {
+        Self::validations(&ctx, Role::ADMIN)?;
+        ctx.accounts.mint_config.list_mode = list_mode;
+        emit!(ListModeUpdated {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+            list_mode,
+        });
+        Ok(())
+    }
+    /// Pauses all transfers of the mint
+    pub fn pause_handler(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        Self::validations(&ctx, Role::PAUSER)?;
+        ctx.accounts.mint_config.paused = true;
+        emit!(MintPaused {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+        });
+        Ok(())
+    }
+    /// Resumes transfers of the mint
+    pub fn unpause_handler(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        Self::validations(&ctx, Role::PAUSER)?;
+        ctx.accounts.mint_config.paused = false;
+        emit!(MintUnpaused {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+        });
+        Ok(())
+    }
+    fn validations(ctx: &Context<UpdateMintConfig>, required_role: u8) -> Result<()> {
+        require!(
+            ctx.accounts.mint_config.is_authorized(
+                ctx.accounts.authority.key(),
+                ctx.accounts.authority_role.as_deref(),
+                required_role
+            ),
+            ErrorCode::Unauthorized
+        );
+        Ok(())
+    }
+}
@@ -1,9 +1,15 @@
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
+use state::{
+    block_list::{BlockReason, ListType},
+    mint_config::ListMode,
+};
 
 pub use constants::*;
 pub use instructions::*;
@@ -19,11 +25,34 @@ pub mod block_lists {
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         Initialize::handler(ctx)
     }
-    pub fn create_block_listt(ctx: Context<CreateBlockList>, list_number: u64) -> Result<()> {
-        CreateBlockList::handler(ctx, list_number)
+    pub fn propose_authority(ctx: Context<UpdateAuthority>, new_authority: Pubkey) -> Result<()> {
+        UpdateAuthority::propose_handler(ctx, new_authority)
     }
-    pub fn add_to_block_list(ctx: Context<ManageBlockList>, addresses: Vec<Pubkey>) -> Result<()> {
-        ManageBlockList::add_handler(ctx, addresses)
+    pub fn cancel_authority_transfer(ctx: Context<UpdateAuthority>) -> Result<()> {
+        UpdateAuthority::cancel_handler(ctx)
+    }
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        AcceptAuthority::handler(ctx)
+    }
+    pub fn create_block_listt(
+        ctx: Context<CreateBlockList>,
+        list_number: u64,
+        initial_capacity: u64,
+        list_type: ListType,
+    ) -> Result<()> {
+        CreateBlockList::handler(ctx, list_number, initial_capacity, list_type)
+    }
+    pub fn close_block_list(ctx: Context<CloseBlockList>) -> Result<()> {
+        CloseBlockList::handler(ctx)
+    }
+    pub fn add_to_block_list(
+        ctx: Context<ManageBlockList>,
+        addresses: Vec<Pubkey>,
+        expires_at: i64,
+        reason: BlockReason,
+        case_reference: [u8; 32],
+    ) -> Result<()> {
+        ManageBlockList::add_handler(ctx, addresses, expires_at, reason, case_reference)
     }
     pub fn remove_from_block_list(
         ctx: Context<ManageBlockList>,
@@ -31,6 +60,35 @@ pub mod block_lists {
     ) -> Result<()> {
         ManageBlockList::remove_handler(ctx, addresses)
     }
+    pub fn prune_expired(ctx: Context<PruneBlockList>) -> Result<()> {
+        PruneBlockList::handler(ctx)
+    }
+    pub fn block_address(
+        ctx: Context<BlockAddress>,
+        address: Pubkey,
+        reason: BlockReason,
+        case_reference: [u8; 32],
+    ) -> Result<()> {
+        BlockAddress::handler(ctx, address, reason, case_reference)
+    }
+    pub fn unblock_address(ctx: Context<UnblockAddress>, address: Pubkey) -> Result<()> {
+        UnblockAddress::handler(ctx, address)
+    }
+    pub fn set_list_mode(ctx: Context<UpdateMintConfig>, list_mode: ListMode) -> Result<()> {
+        UpdateMintConfig::set_list_mode_handler(ctx, list_mode)
+    }
+    pub fn pause(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        UpdateMintConfig::pause_handler(ctx)
+    }
+    pub fn unpause(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        UpdateMintConfig::unpause_handler(ctx)
+    }
+    pub fn grant_role(ctx: Context<GrantRole>, holder: Pubkey, roles: u8) -> Result<()> {
+        GrantRole::handler(ctx, holder, roles)
+    }
+    pub fn revoke_role(ctx: Context<RevokeRole>, holder: Pubkey) -> Result<()> {
+        RevokeRole::handler(ctx, holder)
+    }
     pub fn initialize_extra_account_meta_list(
         ctx: Context<InitializeExtraAccountMetaList>,
     ) -> Result<()> {
@@ -1,24 +1,373 @@
-use anchor_lang::prelude::*;
+use {crate::error::ErrorCode, anchor_lang::prelude::*};
 
-#[account]
+/// Denotes whether a list denies or allows the addresses it contains
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ListType {
+    Deny,
+    Allow,
+}
+
+impl TryFrom<u8> for ListType {
+    type Error = ErrorCode;
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(ListType::Deny),
+            1 => Ok(ListType::Allow),
+            _ => Err(ErrorCode::InvalidListType)
+        }
+    }
+}
+
+/// The reason an address was added to a block list or blocked
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BlockReason {
+    Sanctions,
+    Theft,
+    CourtOrder,
+    InternalReview,
+    Other,
+}
+
+impl TryFrom<u8> for BlockReason {
+    type Error = ErrorCode;
+    fn try_from(value: u8) -> std::result::Result<Self, Self::Error> {
+        match value {
+            0 => Ok(BlockReason::Sanctions),
+            1 => Ok(BlockReason::Theft),
+            2 => Ok(BlockReason::CourtOrder),
+            3 => Ok(BlockReason::InternalReview),
+            4 => Ok(BlockReason::Other),
+            _ => Err(ErrorCode::InvalidBlockReason)
+        }
+    }
+}
+
+/// Header of a block list account.
+///
+/// The header is followed by `num_addresses` entries which are kept sorted by address,
+/// allowing lookups to binary search the account data without deserializing it.
+#[account(zero_copy)]
+#[derive(Debug)]
 pub struct BlockList {
     /// The number of this block list, uses array counting
-    /// 
+    ///
     /// 0 == 1st block list
     /// 1 == 2nd block list
     pub block_list_number: u64,
-    pub denied_addresses: Vec<Pubkey>,
+    /// The number of entries stored after the header
+    pub num_addresses: u64,
+    /// token mint the block list is for
+    pub mint: Pubkey,
+    /// The `ListType` of the block list
+    pub list_type: u8,
+    pub padding: [u8; 7],
+}
+
+/// An address stored in a block list
+#[zero_copy]
+#[derive(Debug, PartialEq, Eq)]
+pub struct BlockListEntry {
+    pub address: Pubkey,
+    /// Unix timestamp after which the entry is ignored, 0 if the entry never expires
+    pub expires_at: i64,
+    /// The `BlockReason` the address was added for
+    pub reason: u8,
+    /// Reference to the case the address was added for, such as a ticket or court order number
+    pub case_reference: [u8; 32],
+    pub padding: [u8; 7],
+}
 
+impl BlockListEntry {
+    pub const SIZE: usize = std::mem::size_of::<BlockListEntry>();
+
+    pub fn new(address: Pubkey, expires_at: i64, reason: BlockReason, case_reference: [u8; 32]) -> Self {
+        Self {
+            address,
+            expires_at,
+            reason: reason as u8,
+            case_reference,
+            padding: [0; 7],
+        }
+    }
+    pub fn reason(&self) -> Result<BlockReason> {
+        Ok(BlockReason::try_from(self.reason)?)
+    }
+    /// Returns whether the entry has expired at the unix timestamp `now`
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at != 0 && self.expires_at <= now
+    }
+    /// Returns whether the entry expires after `other`
+    fn outlives(&self, other: &BlockListEntry) -> bool {
+        self.expires_at == 0 || (other.expires_at != 0 && self.expires_at > other.expires_at)
+    }
 }
 
 impl BlockList {
+    /// Size of the discriminator and header preceding the entries
+    pub const HEADER_SIZE: usize = 8 + std::mem::size_of::<BlockList>();
+
     pub const fn space(max_addresses: usize) -> usize {
-        8 +  // discriminator
-        32 + // block list number
-        4 +  // vec length
-        (32 * max_addresses) // addresses
+        Self::HEADER_SIZE + // discriminator and header
+        (BlockListEntry::SIZE * max_addresses) // entries
+    }
+    /// Returns the number of entries an account of `data_len` bytes has room for
+    pub const fn capacity(data_len: usize) -> usize {
+        data_len.saturating_sub(Self::HEADER_SIZE) / BlockListEntry::SIZE
+    }
+    /// Returns the header stored in the block list account data
+    pub fn header(data: &[u8]) -> &BlockList {
+        bytemuck::from_bytes(&data[8..Self::HEADER_SIZE])
+    }
+    fn header_mut(data: &mut [u8]) -> &mut BlockList {
+        bytemuck::from_bytes_mut(&mut data[8..Self::HEADER_SIZE])
+    }
+    /// Returns the entries stored in the block list account data, sorted by address
+    pub fn entries(data: &[u8]) -> &[BlockListEntry] {
+        let num_addresses = Self::header(data).num_addresses as usize;
+        bytemuck::cast_slice(&data[Self::HEADER_SIZE..Self::space(num_addresses)])
+    }
+    fn entries_mut(data: &mut [u8], num_addresses: usize) -> &mut [BlockListEntry] {
+        bytemuck::cast_slice_mut(&mut data[Self::HEADER_SIZE..Self::space(num_addresses)])
+    }
+    fn find(entries: &[BlockListEntry], address: &Pubkey) -> std::result::Result<usize, usize> {
+        entries.binary_search_by(|entry| entry.address.cmp(address))
+    }
+    /// Returns the entry for `authority` if it has not expired at the unix timestamp `now`
+    pub fn get<'a>(data: &'a [u8], authority: &Pubkey, now: i64) -> Option<&'a BlockListEntry> {
+        let entries = Self::entries(data);
+        Self::find(entries, authority)
+            .ok()
+            .map(|index| &entries[index])
+            .filter(|entry| !entry.is_expired(now))
     }
-    pub fn transfer_denied(&self, authority: Pubkey) -> bool {
-        self.denied_addresses.contains(&authority)
+    /// Returns whether the block list contains an entry for `authority` which has not expired at
+    /// the unix timestamp `now`, which is interpreted according to the list type of the block list
+    pub fn contains(data: &[u8], authority: &Pubkey, now: i64) -> bool {
+        Self::get(data, authority, now).is_some()
+    }
+    pub fn list_type(&self) -> Result<ListType> {
+        Ok(ListType::try_from(self.list_type)?)
+    }
+    /// Returns the sorted, deduplicated entries for the subset of `addresses` which are not yet
+    /// in the block list.
+    ///
+    /// Addresses which are already in the block list have their entry replaced instead if it
+    /// expires before `expires_at`, entries are never shortened by being added again.
+    pub fn new_entries(
+        data: &mut [u8],
+        mut addresses: Vec<Pubkey>,
+        expires_at: i64,
+        reason: BlockReason,
+        case_reference: [u8; 32],
+    ) -> Vec<BlockListEntry> {
+        let num_addresses = Self::header(data).num_addresses as usize;
+        let current_entries = Self::entries_mut(data, num_addresses);
+        addresses.sort_unstable();
+        addresses.dedup();
+        addresses
+            .into_iter()
+            .filter_map(|address| {
+                let new_entry = BlockListEntry::new(address, expires_at, reason, case_reference);
+                match Self::find(current_entries, &address) {
+                    Ok(index) => {
+                        let entry = &mut current_entries[index];
+                        if !entry.outlives(&new_entry) {
+                            *entry = new_entry;
+                        }
+                        None
+                    }
+                    Err(_) => Some(new_entry),
+                }
+            })
+            .collect()
+    }
+    /// Merges the sorted `new_entries` into the block list, which must not already contain
+    /// their addresses and must have capacity for them
+    pub fn insert_sorted(data: &mut [u8], new_entries: &[BlockListEntry]) {
+        let num_addresses = Self::header(data).num_addresses as usize;
+        let new_num_addresses = num_addresses + new_entries.len();
+        let entries = Self::entries_mut(data, new_num_addresses);
+
+        // merge from the back so existing entries are only moved once
+        let (mut current, mut new) = (num_addresses, new_entries.len());
+        for slot in (0..new_num_addresses).rev() {
+            if new > 0
+                && (current == 0 || new_entries[new - 1].address > entries[current - 1].address)
+            {
+                entries[slot] = new_entries[new - 1];
+                new -= 1;
+            } else {
+                entries[slot] = entries[current - 1];
+                current -= 1;
+            }
+        }
+        Self::header_mut(data).num_addresses = new_num_addresses as u64;
+    }
+    /// Removes the entries of `addresses` from the block list, keeping the remaining entries sorted
+    pub fn remove_sorted(data: &mut [u8], mut addresses: Vec<Pubkey>) {
+        addresses.sort_unstable();
+        Self::retain(data, |entry| addresses.binary_search(&entry.address).is_err());
+    }
+    /// Removes the entries which have expired at the unix timestamp `now`, keeping the remaining
+    /// entries sorted, and returns the number of entries removed
+    pub fn prune_expired(data: &mut [u8], now: i64) -> usize {
+        let num_addresses = Self::header(data).num_addresses as usize;
+        num_addresses - Self::retain(data, |entry| !entry.is_expired(now))
+    }
+    /// Compacts the entries matching `keep` to the front of the block list, returning the number retained
+    fn retain(data: &mut [u8], keep: impl Fn(&BlockListEntry) -> bool) -> usize {
+        let num_addresses = Self::header(data).num_addresses as usize;
+        let entries = Self::entries_mut(data, num_addresses);
+
+        let mut retained = 0;
+        for index in 0..num_addresses {
+            let entry = entries[index];
+            if keep(&entry) {
+                entries[retained] = entry;
+                retained += 1;
+            }
+        }
+        Self::header_mut(data).num_addresses = retained as u64;
+        retained
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block_list_data(capacity: usize) -> Vec<u64> {
+        // back the account data with u64s so the header is aligned
+        vec![0u64; BlockList::space(capacity).div_ceil(8)]
+    }
+
+    fn add(data: &mut [u8], addresses: Vec<Pubkey>, expires_at: i64) -> usize {
+        let new_entries =
+            BlockList::new_entries(data, addresses, expires_at, BlockReason::Other, [0; 32]);
+        BlockList::insert_sorted(data, &new_entries);
+        new_entries.len()
+    }
+
+    fn is_sorted(data: &[u8]) -> bool {
+        BlockList::entries(data).windows(2).all(|pair| pair[0].address < pair[1].address)
+    }
+
+    #[test]
+    fn test_insert_keeps_addresses_sorted() {
+        let mut data = block_list_data(5);
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+
+        let first = vec![Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique()];
+        add(data, first.clone(), 0);
+
+        // already present and duplicated addresses are only inserted once
+        let second = vec![first[1], Pubkey::new_unique(), Pubkey::new_unique()];
+        assert_eq!(add(data, [second.clone(), second.clone()].concat(), 0), 2);
+
+        assert_eq!(BlockList::entries(data).len(), 5);
+        assert!(is_sorted(data));
+        for address in first.iter().chain(second.iter()) {
+            assert!(BlockList::contains(data, address, 0));
+        }
+        assert!(!BlockList::contains(data, &Pubkey::new_unique(), 0));
+    }
+
+    #[test]
+    fn test_remove_keeps_addresses_sorted() {
+        let mut data = block_list_data(4);
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+
+        let addresses: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        add(data, addresses.clone(), 0);
+
+        BlockList::remove_sorted(data, vec![addresses[2], addresses[0], Pubkey::new_unique()]);
+
+        assert_eq!(BlockList::entries(data).len(), 2);
+        assert!(is_sorted(data));
+        assert!(!BlockList::contains(data, &addresses[0], 0));
+        assert!(BlockList::contains(data, &addresses[1], 0));
+        assert!(!BlockList::contains(data, &addresses[2], 0));
+        assert!(BlockList::contains(data, &addresses[3], 0));
+    }
+
+    #[test]
+    fn test_expired_entries_are_ignored_and_pruned() {
+        let mut data = block_list_data(4);
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+
+        let permanent = Pubkey::new_unique();
+        let temporary: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        add(data, vec![permanent], 0);
+        add(data, temporary.clone(), 100);
+
+        // adding an address again only ever extends its entry
+        add(data, vec![temporary[1]], 200);
+        add(data, vec![temporary[1]], 150);
+        add(data, vec![temporary[2]], 0);
+
+        assert!(BlockList::contains(data, &temporary[0], 99));
+        assert!(!BlockList::contains(data, &temporary[0], 100));
+        assert!(BlockList::contains(data, &temporary[1], 199));
+        assert!(!BlockList::contains(data, &temporary[1], 200));
+        assert!(BlockList::contains(data, &temporary[2], i64::MAX));
+        assert!(BlockList::contains(data, &permanent, i64::MAX));
+
+        assert_eq!(BlockList::prune_expired(data, 100), 1);
+        assert_eq!(BlockList::entries(data).len(), 3);
+        assert!(is_sorted(data));
+        assert_eq!(BlockList::prune_expired(data, 200), 1);
+        assert_eq!(BlockList::prune_expired(data, 200), 0);
+        assert!(BlockList::contains(data, &temporary[2], i64::MAX));
+        assert!(BlockList::contains(data, &permanent, i64::MAX));
+    }
+
+    #[test]
+    fn test_entries_keep_reason_of_longest_hold() {
+        let mut data = block_list_data(1);
+        let data: &mut [u8] = bytemuck::cast_slice_mut(&mut data);
+        assert_eq!(BlockListEntry::SIZE, 80);
+
+        let address = Pubkey::new_unique();
+        let mut case_reference = [0u8; 32];
+        case_reference[..8].copy_from_slice(b"CASE-001");
+        let new_entries = BlockList::new_entries(
+            data,
+            vec![address],
+            100,
+            BlockReason::InternalReview,
+            case_reference,
+        );
+        BlockList::insert_sorted(data, &new_entries);
+
+        // a shorter hold does not replace the entry
+        BlockList::new_entries(data, vec![address], 50, BlockReason::Theft, [1; 32]);
+        let entry = BlockList::get(data, &address, 0).unwrap();
+        assert_eq!(entry.reason().unwrap(), BlockReason::InternalReview);
+        assert_eq!(entry.case_reference, case_reference);
+
+        // a permanent hold replaces the entry along with its reason
+        BlockList::new_entries(data, vec![address], 0, BlockReason::CourtOrder, [2; 32]);
+        let entry = BlockList::get(data, &address, 100).unwrap();
+        assert_eq!(entry.reason().unwrap(), BlockReason::CourtOrder);
+        assert_eq!(entry.case_reference, [2; 32]);
+        assert_eq!(BlockList::entries(data).len(), 1);
+    }
+
+    #[test]
+    fn test_address_limits_match_account_limits() {
+        use anchor_lang::solana_program::{
+            entrypoint::MAX_PERMITTED_DATA_INCREASE, system_instruction::MAX_PERMITTED_DATA_LENGTH,
+        };
+        let per_instruction = crate::MAX_ADDRESSES_PER_INSTRUCTION as usize;
+        let max_addresses = crate::MAX_BLOCK_LIST_ADDRESSES as usize;
+
+        // a newly created block list and each growth must fit in a single allocation
+        assert!(BlockList::space(per_instruction) <= MAX_PERMITTED_DATA_INCREASE);
+        assert!(BlockList::space(per_instruction + 1) > MAX_PERMITTED_DATA_INCREASE);
+
+        assert!(BlockList::space(max_addresses) <= MAX_PERMITTED_DATA_LENGTH as usize);
+        assert!(BlockList::space(max_addresses + 1) > MAX_PERMITTED_DATA_LENGTH as usize);
     }
 }
@@ -0,0 +1,31 @@
+use {crate::block_list::BlockReason, anchor_lang::prelude::*};
+
+/// Marks a single address as blocked for a mint.
+///
+/// Block markers are derived from the mint and blocked address, allowing the transfer hook to
+/// resolve them directly from the token accounts being transferred between, instead of requiring
+/// every blocked address to be stored in a block list.
+#[account]
+pub struct BlockMarker {
+    /// token mint the address is blocked for
+    pub mint: Pubkey,
+    /// The blocked address
+    pub address: Pubkey,
+    /// The reason the address was blocked
+    pub reason: BlockReason,
+    /// Reference to the case the address was blocked for, such as a ticket or court order number
+    pub case_reference: [u8; 32],
+}
+
+impl BlockMarker {
+    pub const fn space() -> usize {
+        8 + // discriminator
+        32 + // mint
+        32 + // address
+        1 + // reason
+        32 // case_reference
+    }
+    pub fn derive_pda(mint: Pubkey, address: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"blocked", mint.as_ref(), address.as_ref()], &crate::ID)
+    }
+}
@@ -0,0 +1,68 @@
+use {crate::role::Role, anchor_lang::prelude::*};
+
+/// Denotes which lists are evaluated by the transfer hook
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ListMode {
+    /// Transfers are denied if any address is in a deny list or has a block marker
+    DenyList,
+    /// Transfers are denied unless every address is in an allow list
+    AllowList,
+    /// Both deny list and allow list semantics apply
+    Both,
+}
+
+impl ListMode {
+    pub fn deny_lists_enabled(&self) -> bool {
+        matches!(self, ListMode::DenyList | ListMode::Both)
+    }
+    pub fn allow_lists_enabled(&self) -> bool {
+        matches!(self, ListMode::AllowList | ListMode::Both)
+    }
+}
+
+/// Per-mint configuration, allowing multiple issuers to share a single deployment
+/// without being able to manage each other's block lists
+#[account]
+pub struct MintConfig {
+    /// token mint the configuration is for
+    pub mint: Pubkey,
+    /// The address which manages the block lists of the mint
+    pub authority: Pubkey,
+    /// The number of block lists created for the mint, used to derive the next block list
+    pub num_block_lists: u64,
+    /// The number of block lists which have not been closed
+    pub num_active_block_lists: u64,
+    /// The lists evaluated by the transfer hook
+    pub list_mode: ListMode,
+    /// Whether transfers of the mint are paused
+    pub paused: bool,
+}
+
+impl MintConfig {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // mint
+        32 + // authority
+        8 + // num_block_lists
+        8 + // num_active_block_lists
+        1 + // list_mode
+        1 // paused
+    }
+    pub fn derive_pda(mint: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"mint_config", mint.as_ref()], &crate::ID)
+    }
+    /// Returns whether `authority` may perform actions requiring `required_role`, either as the
+    /// mint config authority or as the holder of `role`
+    pub fn is_authorized(&self, authority: Pubkey, role: Option<&Role>, required_role: u8) -> bool {
+        self.authority == authority
+            || role.is_some_and(|role| {
+                role.mint == self.mint && role.holder == authority && role.has_role(required_role)
+            })
+    }
+    pub fn increment_and_get_new_list_number(&mut self) -> u64 {
+        let new_list_number = self.num_block_lists;
+        self.num_block_lists = self.num_block_lists.checked_add(1).unwrap();
+        self.num_active_block_lists = self.num_active_block_lists.checked_add(1).unwrap();
+        new_list_number
+    }
+}
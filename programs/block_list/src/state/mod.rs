@@ -1,2 +1,5 @@
 pub mod block_list;
-pub mod management;
\ No newline at end of file
+pub mod block_marker;
+pub mod management;
+pub mod mint_config;
+pub mod role;
@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+
+/// Roles granted to an address for a single mint, allowing the administration of the
+/// mint to be split between several addresses.
+///
+/// The mint config authority implicitly holds every role.
+#[account]
+pub struct Role {
+    /// token mint the roles are granted for
+    pub mint: Pubkey,
+    /// The address the roles are granted to
+    pub holder: Pubkey,
+    /// Bitmask of the granted roles
+    pub roles: u8,
+}
+
+impl Role {
+    /// Grants every role, including granting and revoking roles
+    pub const ADMIN: u8 = 1 << 0;
+    /// Allows adding addresses to block lists and creating block markers
+    pub const LIST_EDITOR: u8 = 1 << 1;
+    /// Allows pausing and unpausing transfers of the mint
+    pub const PAUSER: u8 = 1 << 2;
+    /// Every role which can be granted
+    pub const ALL: u8 = Self::ADMIN | Self::LIST_EDITOR | Self::PAUSER;
+
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // mint
+        32 + // holder
+        1 // roles
+    }
+    pub fn derive_pda(mint: Pubkey, holder: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"role", mint.as_ref(), holder.as_ref()], &crate::ID)
+    }
+    /// Returns whether `roles` is a non-empty set of known roles
+    pub fn is_valid(roles: u8) -> bool {
+        roles != 0 && roles & !Self::ALL == 0
+    }
+    /// Returns whether the holder has `role`, admins hold every role
+    pub fn has_role(&self, role: u8) -> bool {
+        self.roles & (Self::ADMIN | role) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_role() {
+        let mut role = Role {
+            mint: Pubkey::new_unique(),
+            holder: Pubkey::new_unique(),
+            roles: Role::LIST_EDITOR,
+        };
+        assert!(role.has_role(Role::LIST_EDITOR));
+        assert!(!role.has_role(Role::ADMIN));
+        assert!(!role.has_role(Role::PAUSER));
+
+        role.roles = Role::ADMIN;
+        assert!(role.has_role(Role::LIST_EDITOR));
+        assert!(role.has_role(Role::PAUSER));
+
+        assert!(Role::is_valid(Role::LIST_EDITOR | Role::PAUSER));
+        assert!(!Role::is_valid(0));
+        assert!(!Role::is_valid(1 << 7));
+    }
+}
@@ -0,0 +1,144 @@
+use {
+    crate::error::ErrorCode,
+    anchor_lang::{
+        prelude::*,
+        solana_program::{program::invoke, system_instruction},
+    },
+    anchor_spl::token_2022::spl_token_2022::{
+        self,
+        extension::{
+            transfer_hook::{TransferHook, TransferHookAccount},
+            BaseStateWithExtensions, StateWithExtensions,
+        },
+        state::{Account as TokenAccount, Mint},
+    },
+    spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList},
+    spl_transfer_hook_interface::instruction::ExecuteInstruction,
+    spl_type_length_value::state::TlvStateBorrowed,
+};
+
+/// Returns the account metas currently stored in the `ExtraAccountMetaList` account
+fn extra_account_metas(extra_account_meta_list: &AccountInfo) -> Result<Vec<ExtraAccountMeta>> {
+    let data = extra_account_meta_list.try_borrow_data()?;
+    let tlv_state = TlvStateBorrowed::unpack(&data)?;
+    let extra_accounts =
+        ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)?;
+    Ok(extra_accounts.data().to_vec())
+}
+
+/// Appends `account_meta` to the `ExtraAccountMetaList` account, reallocating the account
+/// and topping up its rent from `payer` as needed
+pub fn append_extra_account_meta<'info>(
+    extra_account_meta_list: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    account_meta: ExtraAccountMeta,
+) -> Result<()> {
+    // get current accounts
+    let mut account_metas = extra_account_metas(extra_account_meta_list)?;
+    // add new account
+    account_metas.push(account_meta);
+
+    // calculate account size
+    let new_account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+    // Current balance of the account
+    let current_balance = extra_account_meta_list.lamports();
+    // calculate minimum required lamports
+    let minimum_balance = Rent::get()?.minimum_balance(new_account_size);
+    // If we need more lamports for rent exemption
+    if minimum_balance > current_balance {
+        let lamports_to_add = minimum_balance - current_balance;
+        invoke(
+            &system_instruction::transfer(payer.key, extra_account_meta_list.key, lamports_to_add),
+            &[
+                payer.clone(),
+                extra_account_meta_list.clone(),
+                system_program.clone(),
+            ],
+        )?;
+    }
+
+    // Reallocate the account to the new size
+    if new_account_size > extra_account_meta_list.data_len() {
+        extra_account_meta_list.realloc(new_account_size, false)?;
+    }
+
+    ExtraAccountMetaList::update::<ExecuteInstruction>(
+        &mut extra_account_meta_list.try_borrow_mut_data()?,
+        &account_metas,
+    )?;
+
+    Ok(())
+}
+
+/// Removes `account_meta` from the `ExtraAccountMetaList` account, shrinking the account
+/// and refunding the excess rent to `receiver`
+///
+/// Returns false if the account meta was not in the list
+pub fn remove_extra_account_meta<'info>(
+    extra_account_meta_list: &AccountInfo<'info>,
+    receiver: &AccountInfo<'info>,
+    account_meta: ExtraAccountMeta,
+) -> Result<bool> {
+    // get current accounts
+    let mut account_metas = extra_account_metas(extra_account_meta_list)?;
+    let Some(index) = account_metas.iter().position(|meta| meta.eq(&account_meta)) else {
+        return Ok(false);
+    };
+    account_metas.remove(index);
+
+    ExtraAccountMetaList::update::<ExecuteInstruction>(
+        &mut extra_account_meta_list.try_borrow_mut_data()?,
+        &account_metas,
+    )?;
+
+    // Reallocate the account to the new size
+    let new_account_size = ExtraAccountMetaList::size_of(account_metas.len())?;
+    extra_account_meta_list.realloc(new_account_size, false)?;
+
+    // Refund the lamports no longer needed for rent exemption
+    let minimum_balance = Rent::get()?.minimum_balance(new_account_size);
+    let excess_lamports = extra_account_meta_list
+        .lamports()
+        .saturating_sub(minimum_balance);
+    **extra_account_meta_list.try_borrow_mut_lamports()? -= excess_lamports;
+    **receiver.try_borrow_mut_lamports()? += excess_lamports;
+
+    Ok(true)
+}
+
+/// Validates that `mint` is a Token-2022 mint whose `TransferHook` extension invokes this program,
+/// and whose transfer hook authority is `authority`
+pub fn validate_transfer_hook_mint(mint: &AccountInfo, authority: &Pubkey) -> Result<()> {
+    require_keys_eq!(*mint.owner, spl_token_2022::ID, ErrorCode::InvalidMint);
+    let data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&data)?;
+    let transfer_hook = mint_state
+        .get_extension::<TransferHook>()
+        .map_err(|_| error!(ErrorCode::InvalidMint))?;
+    require!(
+        Option::<Pubkey>::from(transfer_hook.program_id) == Some(crate::ID),
+        ErrorCode::InvalidMint
+    );
+    require!(
+        Option::<Pubkey>::from(transfer_hook.authority) == Some(*authority),
+        ErrorCode::InvalidTransferHookAuthority
+    );
+    Ok(())
+}
+
+/// Validates that `source_token` is a Token-2022 account which is currently being transferred from,
+/// rejecting invocations of the transfer hook which are not made by a Token-2022 transfer
+pub fn assert_is_transferring(source_token: &AccountInfo) -> Result<()> {
+    require_keys_eq!(*source_token.owner, spl_token_2022::ID, ErrorCode::NotTransferring);
+    let data = source_token.try_borrow_data()?;
+    let source_account = StateWithExtensions::<TokenAccount>::unpack(&data)?;
+    let transfer_hook_account = source_account
+        .get_extension::<TransferHookAccount>()
+        .map_err(|_| error!(ErrorCode::NotTransferring))?;
+    require!(
+        bool::from(transfer_hook_account.transferring),
+        ErrorCode::NotTransferring
+    );
+    Ok(())
+}
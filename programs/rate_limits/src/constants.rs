@@ -2,3 +2,9 @@ use anchor_lang::prelude::*;
 
 #[constant]
 pub const SEED: &str = "anchor";
+
+/// Address expected to initialize the management account, set at compile time through the
+/// `EXPECTED_ADMIN` environment variable for deployments without an upgrade authority.
+///
+/// When unset, the management account can only be initialized by the upgrade authority.
+pub const EXPECTED_ADMIN: Option<&str> = option_env!("EXPECTED_ADMIN");
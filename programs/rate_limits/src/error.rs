@@ -18,4 +18,26 @@ pub enum RateLimitError {
     InvalidRateLimitType,
     #[msg("Provided account meta list account is invalid")]
     InvalidExtraAccountMetasList,
+    #[msg("Provided program data account is invalid")]
+    InvalidProgramData,
+    #[msg("Expected admin set at compile time is not a valid address")]
+    InvalidExpectedAdmin,
+    #[msg("No authority transfer is pending")]
+    NoPendingAuthority,
+    #[msg("Invalid roles")]
+    InvalidRoles,
+    #[msg("Transfers of the mint are paused")]
+    Paused,
+    #[msg("Mint config is missing or does not belong to the mint")]
+    InvalidMintConfig,
+    #[msg("Mint is not a Token-2022 mint with a transfer hook invoking this program")]
+    InvalidMint,
+    #[msg("Signer is not the transfer hook authority of the mint")]
+    InvalidTransferHookAuthority,
+    #[msg("Transfer hook was not invoked by a Token-2022 transfer")]
+    NotTransferring,
+    #[msg("Authority limit entry of the source owner has not been initialized")]
+    MissingAuthorityLimitEntry,
+    #[msg("Authority based rate limit of the entry has not been closed")]
+    RateLimitNotClosed,
 }
@@ -0,0 +1,122 @@
+use {
+    crate::{authority_rate_limit::LimitDirection, limiters::RateLimitType},
+    anchor_lang::prelude::*,
+};
+
+#[event]
+pub struct AuthorityTransferProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferCancelled {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RoleGranted {
+    pub authority: Pubkey,
+    pub holder: Pubkey,
+    pub roles: u8,
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub authority: Pubkey,
+    pub holder: Pubkey,
+    pub roles: u8,
+}
+
+#[event]
+pub struct MintInitialized {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct MintPaused {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct MintUnpaused {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+}
+
+#[event]
+pub struct RateLimitCreated {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub rate_limit: Pubkey,
+    pub rate_limit_type: RateLimitType,
+    pub period_limit: u64,
+    pub period_duration: u64,
+}
+
+#[event]
+pub struct TokenBucketRateLimitCreated {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub rate_limit: Pubkey,
+    pub capacity: u64,
+    pub refill_rate: u64,
+}
+
+#[event]
+pub struct AuthorityLimitEntryCreated {
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub direction: LimitDirection,
+    pub entry: Pubkey,
+}
+
+#[event]
+pub struct AuthorityLimitEntryClosed {
+    /// The account which paid for the entry and received its rent
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+    pub owner: Pubkey,
+    pub direction: LimitDirection,
+    pub entry: Pubkey,
+}
+
+#[event]
+pub struct RateLimitUpdated {
+    pub authority: Pubkey,
+    pub rate_limit: Pubkey,
+    pub period_limit: u64,
+    pub period_duration: u64,
+    /// Whether the current period was reset
+    pub reset: bool,
+}
+
+#[event]
+pub struct RateLimitClosed {
+    pub authority: Pubkey,
+    pub mint: Pubkey,
+    pub rate_limit: Pubkey,
+}
+
+/// Emitted by the transfer hook for every rate limit a transfer is checked against
+#[event]
+pub struct RateLimitedTransfer {
+    pub mint: Pubkey,
+    pub rate_limit: Pubkey,
+    /// The authority the transfer was rate limited for, if the rate limit is per-authority
+    pub authority: Option<Pubkey>,
+    pub amount: u64,
+    /// The amount which can still be transferred in the current period, or the number of transfers
+    /// which can still be made for transfer count rate limits
+    pub remaining: u64,
+}
@@ -0,0 +1,48 @@
+use {
+    crate::{
+        authority_rate_limit::AuthorityLimitEntry,
+        error::RateLimitError,
+        events::AuthorityLimitEntryClosed,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct CloseLimitEntry<'info> {
+    /// CHECK: receives the rent of the entry, validated to be the account which paid for it
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer,
+        seeds = [entry.direction.entry_seed(), entry.mint.as_ref(), entry.owner.as_ref()],
+        bump,
+    )]
+    pub entry: Account<'info, AuthorityLimitEntry>,
+    /// CHECK: the authority based rate limit the entry belongs to, which must have been closed
+    #[account(
+        seeds = [entry.direction.rate_limit_seed(), entry.mint.as_ref()],
+        bump,
+        constraint = rate_limit.data_is_empty() @ RateLimitError::RateLimitNotClosed,
+    )]
+    pub rate_limit: UncheckedAccount<'info>,
+}
+
+impl CloseLimitEntry<'_> {
+    /// Closes an entry whose authority based rate limit has been closed, refunding its rent to the
+    /// account which paid for it
+    ///
+    /// Anyone can close an entry once the rate limit is closed, as it is no longer used by the transfer hook
+    pub fn handler(ctx: Context<CloseLimitEntry>) -> Result<()> {
+        let entry = &ctx.accounts.entry;
+        emit!(AuthorityLimitEntryClosed {
+            payer: entry.payer,
+            mint: entry.mint,
+            owner: entry.owner,
+            direction: entry.direction,
+            entry: entry.key(),
+        });
+        Ok(())
+    }
+}
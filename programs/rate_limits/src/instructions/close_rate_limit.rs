@@ -0,0 +1,97 @@
+use {
+    crate::{
+        authority_rate_limit::{AuthorityLimitEntry, AuthorityRateLimit},
+        error::RateLimitError,
+        events::RateLimitClosed,
+        management::Management,
+        role::Role,
+        utils::{close_account, remove_extra_account_meta},
+    },
+    anchor_lang::{prelude::*, Discriminator},
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
+};
+
+#[derive(Accounts)]
+pub struct CloseRateLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"management"],
+        bump,
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: validated through account metas
+    pub mint: AccountInfo<'info>,
+    /// CHECK: validated by being removed from the account metas of the mint
+    #[account(
+        mut,
+        owner = crate::ID @ RateLimitError::InvalidRateLimitAccount,
+    )]
+    pub rate_limit: UncheckedAccount<'info>,
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+}
+
+impl CloseRateLimit<'_> {
+    /// Removes the rate limit from the accounts passed into the transfer hook and closes it,
+    /// refunding the rent of both accounts to the authority
+    pub fn handler(ctx: Context<CloseRateLimit>) -> Result<()> {
+        Self::validations(&ctx)?;
+
+        // rate limits are the only writable accounts in the account metas, so only
+        // rate limits of the mint can be removed
+        let removed = remove_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.rate_limit.key(), false, true)?,
+        )?;
+        require!(removed, RateLimitError::InvalidRateLimitAccount);
+
+        // authority based rate limits also resolve the entry of the rate limited owner
+        if ctx.accounts.rate_limit.try_borrow_data()?[0..8].eq(&AuthorityRateLimit::discriminator()) {
+            let direction = AuthorityRateLimit::try_deserialize(
+                &mut &ctx.accounts.rate_limit.try_borrow_data()?[..],
+            )?
+            .direction;
+            remove_extra_account_meta(
+                &ctx.accounts.extra_account_meta_list,
+                &ctx.accounts.authority.to_account_info(),
+                AuthorityLimitEntry::extra_account_meta(direction)?,
+            )?;
+        }
+
+        close_account(
+            &ctx.accounts.rate_limit.to_account_info(),
+            &ctx.accounts.authority.to_account_info(),
+        )?;
+
+        emit!(RateLimitClosed {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            rate_limit: ctx.accounts.rate_limit.key(),
+        });
+        Ok(())
+    }
+    fn validations(ctx: &Context<CloseRateLimit>) -> Result<()> {
+        require!(
+            ctx.accounts.management.is_authorized(
+                ctx.accounts.authority.key(),
+                ctx.accounts.authority_role.as_deref(),
+                Role::RATE_LIMIT_MANAGER
+            ),
+            RateLimitError::Unauthorized
+        );
+        Ok(())
+    }
+}
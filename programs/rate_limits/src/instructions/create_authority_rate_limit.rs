@@ -0,0 +1,115 @@
+use {
+    crate::{
+        authority_rate_limit::{AuthorityLimitEntry, AuthorityRateLimit, LimitDirection},
+        error::RateLimitError, events::RateLimitCreated, management::Management, role::Role,
+        utils::append_extra_account_meta,
+    },
+    anchor_lang::prelude::*,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
+};
+
+#[derive(Accounts)]
+#[instruction(period_limit: u64, period_duration: u64, direction: LimitDirection)]
+pub struct CreateAuthorityBasedRateLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"management"],
+        bump,
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: validated through account metas
+    pub mint: AccountInfo<'info>,
+    #[account(
+        init,
+        seeds = [direction.rate_limit_seed(), mint.key.as_ref()],
+        payer = authority,
+        space = AuthorityRateLimit::space(),
+        bump
+    )]
+    pub rate_limit: Account<'info, AuthorityRateLimit>,
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateAuthorityBasedRateLimit<'_> {
+    /// Creates and initializes a rate limit account, along with the account meta resolving the
+    /// `AuthorityLimitEntry` of the rate limited owner during transfers
+    ///
+    /// `direction` selects between limiting the amount sent by the owner of the source token account
+    /// (`Outbound`) and the amount received by the owner of the destination token account (`Inbound`),
+    /// each mint can have one rate limit in each direction
+    pub fn handler(
+        ctx: Context<CreateAuthorityBasedRateLimit>,
+        period_limit: u64,
+        period_duration: u64,
+        direction: LimitDirection,
+    ) -> Result<()> {
+        Self::validations(&ctx)?;
+
+        // initialize the rate limit
+        {
+            let rate_limit = &mut ctx.accounts.rate_limit;
+            rate_limit.initialize(
+                period_limit,
+                period_duration,
+                Clock::get()?.unix_timestamp,
+                ctx.accounts.mint.key(),
+                direction,
+                ctx.bumps.rate_limit,
+            )?;
+        }
+
+        // add the rate limit to the accounts passed into the transfer hook
+        append_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.rate_limit.key(), false, true)?,
+        )?;
+        append_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            AuthorityLimitEntry::extra_account_meta(direction)?,
+        )?;
+
+        emit!(RateLimitCreated {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            rate_limit: ctx.accounts.rate_limit.key(),
+            rate_limit_type: direction.rate_limit_type(),
+            period_limit,
+            period_duration,
+        });
+        Ok(())
+    }
+    fn validations(ctx: &Context<CreateAuthorityBasedRateLimit>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .management
+                .is_authorized(
+                    ctx.accounts.authority.key(),
+                    ctx.accounts.authority_role.as_deref(),
+                    Role::RATE_LIMIT_MANAGER
+                ),
+            RateLimitError::Unauthorized
+        );
+
+        Ok(())
+    }
+}
@@ -1,23 +1,15 @@
 use {
     crate::{
-        error::RateLimitError, limiters::RateLimitType, management::Management,
-        mint_rate_limit::MintRateLimit,
+        error::RateLimitError, events::RateLimitCreated, limiters::RateLimitType,
+        management::Management, mint_rate_limit::MintRateLimit, role::Role,
+        sliding_window_rate_limit::SlidingWindowRateLimit, utils::append_extra_account_meta,
     },
-    anchor_lang::{
-        prelude::*,
-        solana_program::{
-            program::{invoke, invoke_signed},
-            system_instruction,
-        },
-    },
-    spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList},
-    spl_transfer_hook_interface::instruction::{
-        ExecuteInstruction, UpdateExtraAccountMetaListInstruction,
-    },
-    spl_type_length_value::state::TlvStateBorrowed,
+    anchor_lang::prelude::*,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
 };
 
 #[derive(Accounts)]
+#[instruction(index: u8, rate_limit_type: RateLimitType)]
 pub struct CreateMintBasedRateLimit<'info> {
     #[account(mut)]
     pub authority: Signer<'info>,
@@ -27,17 +19,23 @@ pub struct CreateMintBasedRateLimit<'info> {
         bump,
     )]
     pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
     /// CHECK: validated through account metas
     pub mint: AccountInfo<'info>,
-    /// CHECK: vlaidated manually
+    /// CHECK: initialized by the handler according to `rate_limit_type`
     #[account(
         init,
-        seeds = [b"mint_based", mint.key.as_ref()],
+        seeds = [b"mint_based", mint.key.as_ref(), &[index]],
         payer = authority,
-        space = MintRateLimit::space(),
+        space = CreateMintBasedRateLimit::space(rate_limit_type),
         bump
     )]
-    pub rate_limit: Account<'info, MintRateLimit>,
+    pub rate_limit: UncheckedAccount<'info>,
     /// CHECK: ExtraAccountMetaList Account, must use these seeds
     #[account(
         mut,
@@ -51,8 +49,14 @@ pub struct CreateMintBasedRateLimit<'info> {
 
 impl CreateMintBasedRateLimit<'_> {
     /// Creates and initializes a rate limit account, which sets the current period start to the current time
+    ///
+    /// Several rate limits can be created for the same mint using different indexes, all of which
+    /// are evaluated by the transfer hook. `rate_limit_type` selects between a fixed window
+    /// (`MintBased`) and a sliding window (`SlidingWindow`) rate limit
     pub fn handler(
         ctx: Context<CreateMintBasedRateLimit>,
+        index: u8,
+        rate_limit_type: RateLimitType,
         period_limit: u64,
         period_duration: u64,
     ) -> Result<()> {
@@ -60,66 +64,56 @@ impl CreateMintBasedRateLimit<'_> {
 
         // initialize the rate limit
         {
-            let rate_limit= &mut ctx.accounts.rate_limit;
-            rate_limit.initialize(
-                period_limit,
-                period_duration,
-                Clock::get()?.unix_timestamp,
-                ctx.accounts.mint.key(),
-            )?;
-        }
-
-        // get current accounts
-        let mut account_metas: Vec<ExtraAccountMeta> = {
-            let data = ctx.accounts.extra_account_meta_list.try_borrow_data()?;
-            let tlv_state = TlvStateBorrowed::unpack(&data)?;
-            let extra_accounts =
-                ExtraAccountMetaList::unpack_with_tlv_state::<ExecuteInstruction>(&tlv_state)?;
-            extra_accounts.data().to_vec()
-        };
-        // add new account
-        account_metas.push(ExtraAccountMeta::new_with_pubkey(
-            &ctx.accounts.rate_limit.key(),
-            false,
-            true,
-        )?);
-        let account_size = ctx.accounts.extra_account_meta_list.data_len();
-        // calculate account size
-        let data_to_add = ExtraAccountMetaList::size_of(account_metas.len())? as u64;
-        let new_account_size = account_size + data_to_add as usize;
-        // Current balance of the account
-        let current_balance = ctx.accounts.extra_account_meta_list.lamports();
-        // calculate minimum required lamports
-        let minimum_balance = Rent::get()?.minimum_balance(new_account_size as usize);
-        // If we need more lamports for rent exemption
-        if minimum_balance > current_balance {
-            let lamports_to_add = minimum_balance - current_balance;
-            invoke(
-                &system_instruction::transfer(
-                    ctx.accounts.authority.key,
-                    ctx.accounts.extra_account_meta_list.key,
-                    lamports_to_add,
-                ),
-                &[
-                    ctx.accounts.authority.to_account_info(),
-                    ctx.accounts.extra_account_meta_list.to_account_info(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-            )?;
+            let start_time = Clock::get()?.unix_timestamp;
+            let mint = ctx.accounts.mint.key();
+            let bump = ctx.bumps.rate_limit;
+            let mut data = ctx.accounts.rate_limit.try_borrow_mut_data()?;
+            match rate_limit_type {
+                RateLimitType::MintBased => {
+                    MintRateLimit::new(period_limit, period_duration, start_time, mint, index, bump)?
+                        .try_serialize(&mut &mut data[..])?
+                }
+                RateLimitType::SlidingWindow => {
+                    SlidingWindowRateLimit::new(period_limit, period_duration, start_time, mint, index, bump)?
+                        .try_serialize(&mut &mut data[..])?
+                }
+                RateLimitType::AuthorityBased
+                | RateLimitType::TransferCount
+                | RateLimitType::Inbound => {
+                    return Err(RateLimitError::InvalidRateLimitType.into())
+                }
+            }
         }
 
-        // Reallocate the account to the new size
-        ctx.accounts
-            .extra_account_meta_list
-            .realloc(new_account_size as usize, false)?;
-
-        ExtraAccountMetaList::update::<ExecuteInstruction>(
-            &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
-            &account_metas,
+        // add the rate limit to the accounts passed into the transfer hook
+        append_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.rate_limit.key(), false, true)?,
         )?;
 
+        emit!(RateLimitCreated {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            rate_limit: ctx.accounts.rate_limit.key(),
+            rate_limit_type,
+            period_limit,
+            period_duration,
+        });
         Ok(())
     }
+    /// Returns the space required by the rate limit account of the given type
+    fn space(rate_limit_type: RateLimitType) -> usize {
+        match rate_limit_type {
+            RateLimitType::MintBased => MintRateLimit::space(),
+            RateLimitType::SlidingWindow => SlidingWindowRateLimit::space(),
+            // rejected by the handler
+            RateLimitType::AuthorityBased
+            | RateLimitType::TransferCount
+            | RateLimitType::Inbound => 8,
+        }
+    }
     // returns the nocne used to derive the rate limit account
     fn validations(
         ctx: &Context<CreateMintBasedRateLimit>,
@@ -127,8 +121,12 @@ impl CreateMintBasedRateLimit<'_> {
         require!(
             ctx.accounts
                 .management
-                .is_authorized(ctx.accounts.authority.key()),
-                RateLimitError::Unauthorized
+                .is_authorized(
+                    ctx.accounts.authority.key(),
+                    ctx.accounts.authority_role.as_deref(),
+                    Role::RATE_LIMIT_MANAGER
+                ),
+            RateLimitError::Unauthorized
         );
 
         Ok(())
@@ -0,0 +1,101 @@
+use {
+    crate::{
+        error::RateLimitError, events::TokenBucketRateLimitCreated, management::Management,
+        role::Role, token_bucket_rate_limit::TokenBucketRateLimit,
+        utils::append_extra_account_meta,
+    },
+    anchor_lang::prelude::*,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
+};
+
+#[derive(Accounts)]
+pub struct CreateTokenBucketRateLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"management"],
+        bump,
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: validated through account metas
+    pub mint: AccountInfo<'info>,
+    #[account(
+        init,
+        seeds = [b"token_bucket", mint.key.as_ref()],
+        payer = authority,
+        space = TokenBucketRateLimit::space(),
+        bump
+    )]
+    pub rate_limit: Account<'info, TokenBucketRateLimit>,
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateTokenBucketRateLimit<'_> {
+    /// Creates and initializes a full token bucket holding up to `capacity` tokens, which is
+    /// refilled at `refill_rate` tokens per second
+    pub fn handler(
+        ctx: Context<CreateTokenBucketRateLimit>,
+        capacity: u64,
+        refill_rate: u64,
+    ) -> Result<()> {
+        Self::validations(&ctx)?;
+
+        // initialize the rate limit
+        {
+            let rate_limit = &mut ctx.accounts.rate_limit;
+            rate_limit.initialize(
+                capacity,
+                refill_rate,
+                Clock::get()?.unix_timestamp,
+                ctx.accounts.mint.key(),
+                ctx.bumps.rate_limit,
+            )?;
+        }
+
+        // add the rate limit to the accounts passed into the transfer hook
+        append_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.rate_limit.key(), false, true)?,
+        )?;
+
+        emit!(TokenBucketRateLimitCreated {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            rate_limit: ctx.accounts.rate_limit.key(),
+            capacity,
+            refill_rate,
+        });
+        Ok(())
+    }
+    fn validations(ctx: &Context<CreateTokenBucketRateLimit>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .management
+                .is_authorized(
+                    ctx.accounts.authority.key(),
+                    ctx.accounts.authority_role.as_deref(),
+                    Role::RATE_LIMIT_MANAGER
+                ),
+            RateLimitError::Unauthorized
+        );
+
+        Ok(())
+    }
+}
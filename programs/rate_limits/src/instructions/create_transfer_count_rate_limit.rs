@@ -0,0 +1,112 @@
+use {
+    crate::{
+        error::RateLimitError, events::RateLimitCreated, limiters::RateLimitType,
+        management::Management, role::Role, transfer_count_rate_limit::TransferCountRateLimit,
+        utils::append_extra_account_meta,
+    },
+    anchor_lang::prelude::*,
+    spl_tlv_account_resolution::account::ExtraAccountMeta,
+};
+
+#[derive(Accounts)]
+#[instruction(limited_authority: Option<Pubkey>)]
+pub struct CreateTransferCountRateLimit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"management"],
+        bump,
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: validated through account metas
+    pub mint: AccountInfo<'info>,
+    #[account(
+        init,
+        seeds = [
+            b"transfer_count",
+            mint.key.as_ref(),
+            limited_authority.unwrap_or_default().as_ref(),
+        ],
+        payer = authority,
+        space = TransferCountRateLimit::space(),
+        bump
+    )]
+    pub rate_limit: Account<'info, TransferCountRateLimit>,
+    /// CHECK: ExtraAccountMetaList Account, must use these seeds
+    #[account(
+        mut,
+        seeds = [b"extra-account-metas", mint.key().as_ref()],
+        bump
+    )]
+    pub extra_account_meta_list: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl CreateTransferCountRateLimit<'_> {
+    /// Creates and initializes a rate limit allowing `period_limit` transfers per period, which sets the
+    /// current period start to the current time
+    ///
+    /// If `limited_authority` is set only transfers from token accounts it owns are counted, otherwise all
+    /// transfers of the mint are counted
+    pub fn handler(
+        ctx: Context<CreateTransferCountRateLimit>,
+        limited_authority: Option<Pubkey>,
+        period_limit: u64,
+        period_duration: u64,
+    ) -> Result<()> {
+        Self::validations(&ctx)?;
+
+        // initialize the rate limit
+        {
+            let rate_limit = &mut ctx.accounts.rate_limit;
+            rate_limit.initialize(
+                period_limit,
+                period_duration,
+                Clock::get()?.unix_timestamp,
+                ctx.accounts.mint.key(),
+                limited_authority,
+                ctx.bumps.rate_limit,
+            )?;
+        }
+
+        // add the rate limit to the accounts passed into the transfer hook
+        append_extra_account_meta(
+            &ctx.accounts.extra_account_meta_list,
+            &ctx.accounts.authority.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.rate_limit.key(), false, true)?,
+        )?;
+
+        emit!(RateLimitCreated {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint.key(),
+            rate_limit: ctx.accounts.rate_limit.key(),
+            rate_limit_type: RateLimitType::TransferCount,
+            period_limit,
+            period_duration,
+        });
+        Ok(())
+    }
+    fn validations(ctx: &Context<CreateTransferCountRateLimit>) -> Result<()> {
+        require!(
+            ctx.accounts
+                .management
+                .is_authorized(
+                    ctx.accounts.authority.key(),
+                    ctx.accounts.authority_role.as_deref(),
+                    Role::RATE_LIMIT_MANAGER
+                ),
+            RateLimitError::Unauthorized
+        );
+
+        Ok(())
+    }
+}
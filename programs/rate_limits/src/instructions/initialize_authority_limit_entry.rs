@@ -0,0 +1,53 @@
+use {
+    crate::{
+        authority_rate_limit::{AuthorityLimitEntry, AuthorityRateLimit, LimitDirection},
+        events::AuthorityLimitEntryCreated,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(owner: Pubkey, direction: LimitDirection)]
+pub struct InitializeAuthorityLimitEntry<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: validated through the rate limit seeds
+    pub mint: AccountInfo<'info>,
+    /// The authority based rate limit of the mint in `direction`, entries can only be created for rate limited mints
+    #[account(
+        seeds = [direction.rate_limit_seed(), mint.key.as_ref()],
+        bump,
+    )]
+    pub rate_limit: Account<'info, AuthorityRateLimit>,
+    #[account(
+        init,
+        seeds = [direction.entry_seed(), mint.key.as_ref(), owner.as_ref()],
+        payer = payer,
+        space = AuthorityLimitEntry::space(),
+        bump
+    )]
+    pub entry: Account<'info, AuthorityLimitEntry>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl InitializeAuthorityLimitEntry<'_> {
+    /// Creates the entry tracking the value sent or received by `owner`, according to `direction`.
+    /// Entries of outbound rate limits must exist before `owner` can send tokens of the mint, while
+    /// transfers to owners without an inbound entry are checked as if their entry was new
+    ///
+    /// Anyone can create an entry, the period of the entry starts at its first transfer
+    pub fn handler(ctx: Context<InitializeAuthorityLimitEntry>, owner: Pubkey, direction: LimitDirection) -> Result<()> {
+        let entry = &mut ctx.accounts.entry;
+        **entry = AuthorityLimitEntry::new(ctx.accounts.mint.key(), owner, ctx.accounts.payer.key(), direction, ctx.bumps.entry);
+
+        emit!(AuthorityLimitEntryCreated {
+            payer: ctx.accounts.payer.key(),
+            mint: ctx.accounts.mint.key(),
+            owner,
+            direction,
+            entry: entry.key(),
+        });
+        Ok(())
+    }
+}
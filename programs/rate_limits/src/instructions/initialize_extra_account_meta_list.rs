@@ -1,15 +1,13 @@
 use {
-    crate::{error::RateLimitError, management::Management},
-    anchor_lang::{
-        prelude::*, solana_program::{program::invoke_signed, system_instruction}, system_program::{create_account, CreateAccount}
+    crate::{
+        error::RateLimitError, events::MintInitialized, management::Management,
+        mint_config::MintConfig, role::Role, utils::validate_transfer_hook_mint,
     },
-    anchor_spl::{
-        associated_token::AssociatedToken,
-        token_2022::{
-            spl_token_2022::{extension::StateWithExtensions, state::Mint},
-            Token2022,
-        },
+    anchor_lang::{
+        prelude::*,
+        system_program::{create_account, CreateAccount},
     },
+    anchor_spl::{associated_token::AssociatedToken, token_2022::Token2022},
     spl_tlv_account_resolution::{account::ExtraAccountMeta, state::ExtraAccountMetaList},
     spl_transfer_hook_interface::instruction::ExecuteInstruction,
 };
@@ -19,10 +17,27 @@ pub struct InitializeExtraAccountMetaList<'info> {
     #[account(mut)]
     authority: Signer<'info>,
     #[account(
-        constraint = management.authority == authority.key() @ RateLimitError::Unauthorized
+        seeds = [b"management"],
+        bump,
+        constraint = management.is_authorized(authority.key(), authority_role.as_deref(), Role::ADMIN) @ RateLimitError::Unauthorized
     )]
     pub management: Account<'info, Management>,
-
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// The transfer hook authority of the mint, which must consent to the mint being onboarded
+    pub hook_authority: Signer<'info>,
+    #[account(
+        init,
+        seeds = [b"mint_config", mint.key().as_ref()],
+        payer = authority,
+        space = MintConfig::space(),
+        bump,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
     /// CHECK: ExtraAccountMetaList Account, must use these seeds
     #[account(
         mut,
@@ -39,6 +54,13 @@ pub struct InitializeExtraAccountMetaList<'info> {
 
 impl InitializeExtraAccountMetaList<'_> {
     pub fn handler(ctx: Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        Self::validations(&ctx)?;
+        {
+            let mint_config = &mut ctx.accounts.mint_config;
+            mint_config.mint = ctx.accounts.mint.key();
+            mint_config.paused = false;
+        }
+
         // index 0-3 are the accounts required for token transfer (source, mint, destination, owner)
         // index 4 is address of ExtraAccountMetaList account
         let account_metas = vec![
@@ -50,8 +72,8 @@ impl InitializeExtraAccountMetaList<'_> {
                 false,
                 false,
             )?,
-            // index 7
-            //ExtraAccountMeta::new_with_pubkey(&ctx.accounts.block_list.key(), false, false)?,
+            // index 7, mint config
+            ExtraAccountMeta::new_with_pubkey(&ctx.accounts.mint_config.key(), false, false)?,
         ];
 
         // calculate account size
@@ -62,7 +84,7 @@ impl InitializeExtraAccountMetaList<'_> {
         let mint = ctx.accounts.mint.key();
         let signer_seeds: &[&[&[u8]]] = &[&[
             b"extra-account-metas",
-            &mint.as_ref(),
+            mint.as_ref(),
             &[ctx.bumps.extra_account_meta_list],
         ]];
 
@@ -86,6 +108,13 @@ impl InitializeExtraAccountMetaList<'_> {
             &mut ctx.accounts.extra_account_meta_list.try_borrow_mut_data()?,
             &account_metas,
         )?;
+        emit!(MintInitialized {
+            authority: ctx.accounts.authority.key(),
+            mint,
+        });
         Ok(())
     }
+    fn validations(ctx: &Context<InitializeExtraAccountMetaList>) -> Result<()> {
+        validate_transfer_hook_mint(&ctx.accounts.mint, ctx.accounts.hook_authority.key)
+    }
 }
@@ -0,0 +1,91 @@
+use {
+    crate::{
+        error::RateLimitError,
+        events::{RoleGranted, RoleRevoked},
+        management::Management,
+        role::Role,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"management"],
+        bump,
+        constraint = management.is_authorized(authority.key(), authority_role.as_deref(), Role::ADMIN) @ RateLimitError::Unauthorized
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    #[account(
+        init,
+        seeds = [b"role", holder.as_ref()],
+        payer = authority,
+        space = Role::space(),
+        bump,
+    )]
+    pub role: Account<'info, Role>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(holder: Pubkey)]
+pub struct RevokeRole<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"management"],
+        bump,
+        constraint = management.is_authorized(authority.key(), authority_role.as_deref(), Role::ADMIN) @ RateLimitError::Unauthorized
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"role", holder.as_ref()],
+        bump,
+    )]
+    pub role: Account<'info, Role>,
+}
+
+impl GrantRole<'_> {
+    /// Grants `roles` to `holder`, roles are changed by revoking and granting them again
+    pub fn handler(ctx: Context<GrantRole>, holder: Pubkey, roles: u8) -> Result<()> {
+        require!(Role::is_valid(roles), RateLimitError::InvalidRoles);
+        let role = &mut ctx.accounts.role;
+        role.holder = holder;
+        role.roles = roles;
+        emit!(RoleGranted {
+            authority: ctx.accounts.authority.key(),
+            holder,
+            roles,
+        });
+        Ok(())
+    }
+}
+
+impl RevokeRole<'_> {
+    /// Closes the role account of `holder`, refunding the rent to the authority
+    pub fn handler(ctx: Context<RevokeRole>, holder: Pubkey) -> Result<()> {
+        emit!(RoleRevoked {
+            authority: ctx.accounts.authority.key(),
+            holder,
+            roles: ctx.accounts.role.roles,
+        });
+        Ok(())
+    }
+}
@@ -1,9 +1,29 @@
 pub mod initialize;
 pub mod initialize_extra_account_meta_list;
 pub mod create_mint_rate_limit;
+pub mod create_authority_rate_limit;
+pub mod create_token_bucket_rate_limit;
+pub mod create_transfer_count_rate_limit;
+pub mod initialize_authority_limit_entry;
+pub mod close_rate_limit;
+pub mod close_limit_entry;
+pub mod manage_role;
+pub mod transfer_authority;
 pub mod transfer_hook;
+pub mod update_mint_config;
+pub mod update_rate_limit;
 
 pub use initialize::*;
 pub use initialize_extra_account_meta_list::*;
 pub use create_mint_rate_limit::*;
-pub use transfer_hook::*;
\ No newline at end of file
+pub use create_authority_rate_limit::*;
+pub use create_token_bucket_rate_limit::*;
+pub use create_transfer_count_rate_limit::*;
+pub use initialize_authority_limit_entry::*;
+pub use close_rate_limit::*;
+pub use close_limit_entry::*;
+pub use manage_role::*;
+pub use transfer_authority::*;
+pub use transfer_hook::*;
+pub use update_mint_config::*;
+pub use update_rate_limit::*;
@@ -0,0 +1,75 @@
+use {
+    crate::{
+        error::RateLimitError,
+        events::{AuthorityTransferCancelled, AuthorityTransferProposed, AuthorityTransferred},
+        management::Management,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateAuthority<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"management"],
+        bump,
+        constraint = management.authority == authority.key() @ RateLimitError::Unauthorized
+    )]
+    pub management: Account<'info, Management>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    /// The proposed authority, which must sign to accept the transfer
+    pub new_authority: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"management"],
+        bump,
+        constraint = management.pending_authority.is_some() @ RateLimitError::NoPendingAuthority,
+        constraint = management.pending_authority == Some(new_authority.key()) @ RateLimitError::Unauthorized
+    )]
+    pub management: Account<'info, Management>,
+}
+
+impl UpdateAuthority<'_> {
+    /// Proposes `new_authority` as the management authority, which takes effect once accepted
+    pub fn propose_handler(ctx: Context<UpdateAuthority>, new_authority: Pubkey) -> Result<()> {
+        ctx.accounts.management.pending_authority = Some(new_authority);
+        emit!(AuthorityTransferProposed {
+            authority: ctx.accounts.authority.key(),
+            pending_authority: new_authority,
+        });
+        Ok(())
+    }
+    /// Cancels a pending authority transfer
+    pub fn cancel_handler(ctx: Context<UpdateAuthority>) -> Result<()> {
+        let pending_authority = ctx
+            .accounts
+            .management
+            .pending_authority
+            .take()
+            .ok_or(RateLimitError::NoPendingAuthority)?;
+        emit!(AuthorityTransferCancelled {
+            authority: ctx.accounts.authority.key(),
+            pending_authority,
+        });
+        Ok(())
+    }
+}
+
+impl AcceptAuthority<'_> {
+    /// Completes a pending authority transfer
+    pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+        let management = &mut ctx.accounts.management;
+        let previous_authority = management.authority;
+        management.authority = ctx.accounts.new_authority.key();
+        management.pending_authority = None;
+        emit!(AuthorityTransferred {
+            previous_authority,
+            new_authority: management.authority,
+        });
+        Ok(())
+    }
+}
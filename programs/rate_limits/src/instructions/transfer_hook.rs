@@ -1,5 +1,12 @@
 use {
-    crate::{authority_rate_limit::AuthorityRateLimit, error::RateLimitError, limiters::RateLimitExt, mint_rate_limit::MintRateLimit},
+    crate::{
+        authority_rate_limit::{AuthorityLimitEntry, AuthorityRateLimit, LimitDirection},
+        error::RateLimitError,
+        events::RateLimitedTransfer, limiters::{validate_rate_limit_account, RateLimitExt}, mint_config::MintConfig,
+        mint_rate_limit::MintRateLimit, sliding_window_rate_limit::SlidingWindowRateLimit,
+        token_bucket_rate_limit::TokenBucketRateLimit,
+        transfer_count_rate_limit::TransferCountRateLimit, utils::assert_is_transferring,
+    },
     anchor_lang::{prelude::*, Discriminator},
     anchor_spl::{
         associated_token::AssociatedToken,
@@ -40,7 +47,8 @@ impl TransferHook<'_> {
             ctx.accounts.extra_account_meta_list.owner.eq(&crate::ID),
             RateLimitError::InvalidExtraAccountMetasList
         );
-        if ctx.remaining_accounts.len() == 0 {
+        assert_is_transferring(&ctx.accounts.source_token)?;
+        if ctx.remaining_accounts.is_empty() {
             panic!("unexpected condition")
         }
         // build list of owners to check which will be evaluated against all block lists
@@ -52,27 +60,351 @@ impl TransferHook<'_> {
             let source_account = StateWithExtensions::<TokenAccount>::unpack(&data)?;
             owners_to_check.push(source_account.base.owner);
         }
+        let source_owner = owners_to_check[1];
         {
             // check to see if receiving token account owner is denied
             let data = ctx.accounts.destination_token.data.try_borrow().unwrap();
             let receiving_account = StateWithExtensions::<TokenAccount>::unpack(&data)?;
             owners_to_check.push(receiving_account.base.owner);
         }
+        let destination_owner = owners_to_check[2];
 
-        // evaluate all block lists to see if any of the owners are denied
+        // evaluate all rate limits, failing if the mint is paused or any account does not belong to the mint.
+        //
+        // rate limits are only written back once every rate limit has accepted the transfer, so that
+        // a transfer exceeding any one window never updates the others
+        let mut mint_config_found = false;
+        let mut updated: Vec<Box<dyn AccountsExit<'info>>> = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut events: Vec<RateLimitedTransfer> = Vec::with_capacity(ctx.remaining_accounts.len());
+        let mut authority_rate_limits: Vec<Account<AuthorityRateLimit>> = Vec::with_capacity(2);
+        let mut authority_limit_entries: Vec<Account<AuthorityLimitEntry>> = Vec::with_capacity(2);
+        let mut missing_entries: usize = 0;
         for remaining_account in ctx.remaining_accounts.iter() {
+            if remaining_account.data_is_empty() {
+                // the entries of the source and destination owners are resolved by the account metas even if
+                // they were never created. empty accounts are never read or written, so they are only counted
+                // against the authority based rate limits without an entry below
+                missing_entries += 1;
+                continue;
+            }
             let mut discriminator: [u8; 8] = [0u8; 8];
             discriminator.copy_from_slice(&remaining_account.try_borrow_data()?[0..8]);
-            if MintRateLimit::discriminator().eq(&discriminator) {
+            if MintConfig::discriminator().eq(&discriminator) {
+                let mint_config: Account<MintConfig> = Account::try_from(remaining_account)?;
+                require!(
+                    mint_config.mint.eq(ctx.accounts.mint.key),
+                    RateLimitError::InvalidMintConfig
+                );
+                require!(!mint_config.paused, RateLimitError::Paused);
+                mint_config_found = true;
+            } else if MintRateLimit::discriminator().eq(&discriminator) {
                 let mut rate_limit: Account<MintRateLimit> = Account::try_from(remaining_account)?;
+                validate_rate_limit_account(
+                    remaining_account.key,
+                    &rate_limit.mint,
+                    ctx.accounts.mint.key,
+                    rate_limit.pda()?,
+                )?;
+                rate_limit.check_and_update(None, amount)?;
+                let event = RateLimitedTransfer {
+                    mint: ctx.accounts.mint.key(),
+                    rate_limit: remaining_account.key(),
+                    authority: None,
+                    amount,
+                    remaining: rate_limit.remaining(None),
+                };
+                updated.push(Box::new(rate_limit));
+                events.push(event);
+            } else if SlidingWindowRateLimit::discriminator().eq(&discriminator) {
+                let mut rate_limit: Account<SlidingWindowRateLimit> = Account::try_from(remaining_account)?;
+                validate_rate_limit_account(
+                    remaining_account.key,
+                    &rate_limit.mint,
+                    ctx.accounts.mint.key,
+                    rate_limit.pda()?,
+                )?;
                 rate_limit.check_and_update(None, amount)?;
-                rate_limit.exit(&crate::ID)?;
+                let event = RateLimitedTransfer {
+                    mint: ctx.accounts.mint.key(),
+                    rate_limit: remaining_account.key(),
+                    authority: None,
+                    amount,
+                    remaining: rate_limit.remaining(None),
+                };
+                updated.push(Box::new(rate_limit));
+                events.push(event);
+            } else if TokenBucketRateLimit::discriminator().eq(&discriminator) {
+                let mut rate_limit: Account<TokenBucketRateLimit> = Account::try_from(remaining_account)?;
+                validate_rate_limit_account(
+                    remaining_account.key,
+                    &rate_limit.mint,
+                    ctx.accounts.mint.key,
+                    rate_limit.pda()?,
+                )?;
+                rate_limit.check_and_update(None, amount)?;
+                let event = RateLimitedTransfer {
+                    mint: ctx.accounts.mint.key(),
+                    rate_limit: remaining_account.key(),
+                    authority: None,
+                    amount,
+                    remaining: rate_limit.remaining(None),
+                };
+                updated.push(Box::new(rate_limit));
+                events.push(event);
+            } else if TransferCountRateLimit::discriminator().eq(&discriminator) {
+                let mut rate_limit: Account<TransferCountRateLimit> = Account::try_from(remaining_account)?;
+                validate_rate_limit_account(
+                    remaining_account.key,
+                    &rate_limit.mint,
+                    ctx.accounts.mint.key,
+                    rate_limit.pda()?,
+                )?;
+                // rate limits of other authorities do not count this transfer. transfers are attributed
+                // to the source owner, so signing through a delegate does not bypass the limit
+                if rate_limit.applies_to(Some(source_owner)) {
+                    rate_limit.check_and_update(Some(source_owner), amount)?;
+                    let event = RateLimitedTransfer {
+                        mint: ctx.accounts.mint.key(),
+                        rate_limit: remaining_account.key(),
+                        authority: rate_limit.authority,
+                        amount,
+                        remaining: rate_limit.remaining(Some(source_owner)),
+                    };
+                    updated.push(Box::new(rate_limit));
+                events.push(event);
+                }
             } else if AuthorityRateLimit::discriminator().eq(&discriminator) {
-                panic!("unsupported rate limit");
+                let rate_limit: Account<AuthorityRateLimit> = Account::try_from(remaining_account)?;
+                validate_rate_limit_account(
+                    remaining_account.key,
+                    &rate_limit.mint,
+                    ctx.accounts.mint.key,
+                    rate_limit.pda()?,
+                )?;
+                authority_rate_limits.push(rate_limit);
+            } else if AuthorityLimitEntry::discriminator().eq(&discriminator) {
+                let entry: Account<AuthorityLimitEntry> = Account::try_from(remaining_account)?;
+                require!(
+                    entry.mint.eq(ctx.accounts.mint.key)
+                        && entry.owner.eq(&entry.direction.owner(source_owner, destination_owner))
+                        && remaining_account.key.eq(&entry.pda()?),
+                    RateLimitError::InvalidRateLimitAccount
+                );
+                authority_limit_entries.push(entry);
             } else {
                 return Err(RateLimitError::InvalidRateLimitAccount.into())
             }
         }
+        require!(mint_config_found, RateLimitError::InvalidMintConfig);
+
+        // authority based rate limits track each rate limited owner in its own entry
+        for rate_limit in authority_rate_limits.iter() {
+            let entry = authority_limit_entries
+                .iter()
+                .position(|entry| entry.direction == rate_limit.direction)
+                .map(|index| authority_limit_entries.swap_remove(index));
+            if entry.is_none() {
+                missing_entries = missing_entries.checked_sub(1).ok_or(RateLimitError::InvalidRateLimitAccount)?;
+            }
+            let owner = rate_limit.direction.owner(source_owner, destination_owner);
+            let (entry, event) = Self::check_authority_rate_limit(rate_limit, entry, owner, amount)?;
+            if let Some(entry) = entry {
+                updated.push(Box::new(entry));
+            }
+            events.push(event);
+        }
+        // every empty account must stand in for the entry of an authority based rate limit
+        require!(missing_entries == 0, RateLimitError::InvalidRateLimitAccount);
+
+        for rate_limit in updated {
+            rate_limit.exit(&crate::ID)?;
+        }
+        for event in events {
+            emit!(event);
+        }
         Ok(())
     }
+    /// Checks the transfer against an authority based rate limit using the entry of `owner`, returning the entry
+    /// to write back if it exists
+    ///
+    /// Owners without an outbound entry can not send tokens. As the transfer hook can not create entries,
+    /// transfers to owners without an inbound entry are checked as if their entry was new, so that new
+    /// recipients can still receive up to the period limit in a single transfer
+    fn check_authority_rate_limit<'info>(
+        rate_limit: &Account<'info, AuthorityRateLimit>,
+        entry: Option<Account<'info, AuthorityLimitEntry>>,
+        owner: Pubkey,
+        amount: u64,
+    ) -> Result<(Option<Account<'info, AuthorityLimitEntry>>, RateLimitedTransfer)> {
+        let (entry, remaining) = match entry {
+            Some(mut entry) => {
+                rate_limit.check_and_update_entry(&mut entry, amount)?;
+                let remaining = rate_limit.remaining_for_entry(&entry);
+                (Some(entry), remaining)
+            }
+            None => {
+                require!(
+                    rate_limit.direction == LimitDirection::Inbound,
+                    RateLimitError::MissingAuthorityLimitEntry
+                );
+                let mut entry = AuthorityLimitEntry::new(rate_limit.mint, owner, Pubkey::default(), rate_limit.direction, 0);
+                rate_limit.check_and_update_entry(&mut entry, amount)?;
+                (None, rate_limit.remaining_for_entry(&entry))
+            }
+        };
+        let event = RateLimitedTransfer {
+            mint: rate_limit.mint,
+            rate_limit: rate_limit.key(),
+            authority: Some(owner),
+            amount,
+            remaining,
+        };
+        Ok((entry, event))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        anchor_spl::token_2022::spl_token_2022::{
+            self,
+            extension::{
+                transfer_hook::TransferHookAccount, BaseStateWithExtensionsMut, ExtensionType,
+                StateWithExtensionsMut,
+            },
+            state::AccountState,
+        },
+    };
+
+    /// Returns an account which lives for the rest of the test, as the accounts of a transfer hook invocation
+    /// are borrowed for the lifetime of the context
+    fn account(key: Pubkey, owner: Pubkey, data: Vec<u8>, executable: bool) -> &'static AccountInfo<'static> {
+        Box::leak(Box::new(AccountInfo::new(
+            Box::leak(Box::new(key)),
+            false,
+            true,
+            Box::leak(Box::new(1_000_000_000)),
+            Box::leak(data.into_boxed_slice()),
+            Box::leak(Box::new(owner)),
+            executable,
+            0,
+        )))
+    }
+
+    /// Returns the data of a token account of `owner` which is in the middle of a transfer
+    fn token_account(mint: Pubkey, owner: Pubkey) -> Vec<u8> {
+        let len = ExtensionType::try_calculate_account_len::<TokenAccount>(&[ExtensionType::TransferHookAccount]).unwrap();
+        let mut data = vec![0; len];
+        let mut state = StateWithExtensionsMut::<TokenAccount>::unpack_uninitialized(&mut data).unwrap();
+        state.base = TokenAccount {
+            mint,
+            owner,
+            state: AccountState::Initialized,
+            ..Default::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        state.init_extension::<TransferHookAccount>(true).unwrap().transferring = true.into();
+        data
+    }
+
+    fn serialize<T: AccountSerialize>(state: &T) -> Vec<u8> {
+        let mut data = Vec::new();
+        state.try_serialize(&mut data).unwrap();
+        data
+    }
+
+    /// Invokes the transfer hook for a transfer of `amount` of `mint` with the given rate limits, after the
+    /// mint config of `mint`
+    fn transfer(mint: Pubkey, rate_limits: Vec<&'static AccountInfo<'static>>, amount: u64) -> Result<()> {
+        let source_owner = Pubkey::new_unique();
+        let mut remaining_accounts = vec![account(
+            MintConfig::derive_pda(mint).0,
+            crate::ID,
+            serialize(&MintConfig { mint, paused: false }),
+            false,
+        )
+        .clone()];
+        remaining_accounts.extend(rate_limits.into_iter().cloned());
+        let remaining_accounts: &'static [AccountInfo<'static>] = Box::leak(remaining_accounts.into_boxed_slice());
+
+        let (extra_account_meta_list, bump) = Pubkey::find_program_address(
+            &[b"extra-account-metas", mint.as_ref()],
+            &crate::ID,
+        );
+        let mut accounts = TransferHook {
+            source_token: UncheckedAccount::try_from(account(
+                Pubkey::new_unique(),
+                spl_token_2022::ID,
+                token_account(mint, source_owner),
+                false,
+            )),
+            mint: UncheckedAccount::try_from(account(mint, spl_token_2022::ID, vec![], false)),
+            destination_token: UncheckedAccount::try_from(account(
+                Pubkey::new_unique(),
+                spl_token_2022::ID,
+                token_account(mint, Pubkey::new_unique()),
+                false,
+            )),
+            owner: UncheckedAccount::try_from(account(source_owner, Pubkey::default(), vec![], false)),
+            extra_account_meta_list: UncheckedAccount::try_from(account(
+                extra_account_meta_list,
+                crate::ID,
+                vec![0; 8],
+                false,
+            )),
+            token_program: Program::try_from(account(spl_token_2022::ID, Pubkey::default(), vec![], true)).unwrap(),
+            associated_token_program: Program::try_from(account(
+                anchor_spl::associated_token::ID,
+                Pubkey::default(),
+                vec![],
+                true,
+            ))
+            .unwrap(),
+        };
+        let ctx = Context::new(
+            &crate::ID,
+            &mut accounts,
+            remaining_accounts,
+            TransferHookBumps { extra_account_meta_list: bump },
+        );
+        TransferHook::handler(ctx, amount)
+    }
+
+    /// Returns the account of a mint based rate limit of `mint` allowing 100 tokens per hour
+    fn mint_rate_limit(mint: Pubkey) -> &'static AccountInfo<'static> {
+        let (key, bump) = MintRateLimit::derive_pda(mint, 0);
+        let rate_limit = MintRateLimit::new(100, 3600, 0, mint, 0, bump).unwrap();
+        account(key, crate::ID, serialize(&rate_limit), false)
+    }
+
+    #[test]
+    fn test_checks_rate_limits_of_the_mint() {
+        let mint = Pubkey::new_unique();
+        let rate_limit = mint_rate_limit(mint);
+
+        assert!(transfer(mint, vec![rate_limit], 60).is_ok());
+        let state = MintRateLimit::try_deserialize(&mut &rate_limit.try_borrow_data().unwrap()[..]).unwrap();
+        assert_eq!(state.value_transferred, 60);
+
+        assert_eq!(
+            transfer(mint, vec![rate_limit], 41).unwrap_err(),
+            RateLimitError::RateLimitExceeded.into()
+        );
+    }
+
+    #[test]
+    fn test_rejects_rate_limit_of_another_mint() {
+        let mint = Pubkey::new_unique();
+        let other_rate_limit = mint_rate_limit(Pubkey::new_unique());
+
+        assert_eq!(
+            transfer(mint, vec![other_rate_limit], 1).unwrap_err(),
+            RateLimitError::InvalidRateLimitAccount.into()
+        );
+        // the rate limit of the other mint is left untouched
+        let state = MintRateLimit::try_deserialize(&mut &other_rate_limit.try_borrow_data().unwrap()[..]).unwrap();
+        assert_eq!(state.value_transferred, 0);
+    }
 }
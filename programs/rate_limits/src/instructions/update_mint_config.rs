@@ -0,0 +1,66 @@
+use {
+    crate::{
+        error::RateLimitError,
+        events::{MintPaused, MintUnpaused},
+        management::Management,
+        mint_config::MintConfig,
+        role::Role,
+    },
+    anchor_lang::prelude::*,
+};
+
+#[derive(Accounts)]
+pub struct UpdateMintConfig<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"management"],
+        bump,
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    #[account(
+        mut,
+        seeds = [b"mint_config", mint_config.mint.as_ref()],
+        bump,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+}
+
+impl UpdateMintConfig<'_> {
+    /// Pauses all transfers of the mint
+    pub fn pause_handler(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        Self::validations(&ctx, Role::PAUSER)?;
+        ctx.accounts.mint_config.paused = true;
+        emit!(MintPaused {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+        });
+        Ok(())
+    }
+    /// Resumes transfers of the mint
+    pub fn unpause_handler(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        Self::validations(&ctx, Role::PAUSER)?;
+        ctx.accounts.mint_config.paused = false;
+        emit!(MintUnpaused {
+            authority: ctx.accounts.authority.key(),
+            mint: ctx.accounts.mint_config.mint,
+        });
+        Ok(())
+    }
+    fn validations(ctx: &Context<UpdateMintConfig>, required_role: u8) -> Result<()> {
+        require!(
+            ctx.accounts.management.is_authorized(
+                ctx.accounts.authority.key(),
+                ctx.accounts.authority_role.as_deref(),
+                required_role
+            ),
+            RateLimitError::Unauthorized
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,94 @@
+use {
+    crate::{
+        authority_rate_limit::AuthorityRateLimit, error::RateLimitError,
+        events::RateLimitUpdated, limiters::RateLimitConfig, management::Management,
+        mint_rate_limit::MintRateLimit, role::Role,
+        sliding_window_rate_limit::SlidingWindowRateLimit,
+        token_bucket_rate_limit::TokenBucketRateLimit,
+        transfer_count_rate_limit::TransferCountRateLimit,
+    },
+    anchor_lang::{prelude::*, Discriminator},
+};
+
+#[derive(Accounts)]
+pub struct UpdateRateLimit<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        seeds = [b"management"],
+        bump,
+    )]
+    pub management: Account<'info, Management>,
+    /// Role of the authority, not required if the authority is the management authority
+    #[account(
+        seeds = [b"role", authority.key().as_ref()],
+        bump,
+    )]
+    pub authority_role: Option<Account<'info, Role>>,
+    /// CHECK: deserialized according to its discriminator
+    #[account(
+        mut,
+        owner = crate::ID @ RateLimitError::InvalidRateLimitAccount,
+    )]
+    pub rate_limit: UncheckedAccount<'info>,
+}
+
+impl UpdateRateLimit<'_> {
+    /// Updates the limit and duration of any type of rate limit, preserving the current period
+    /// unless `reset` is true
+    pub fn handler(
+        ctx: Context<UpdateRateLimit>,
+        period_limit: u64,
+        period_duration: u64,
+        reset: bool,
+    ) -> Result<()> {
+        Self::validations(&ctx)?;
+
+        let rate_limit = &ctx.accounts.rate_limit;
+        let mut discriminator: [u8; 8] = [0u8; 8];
+        discriminator.copy_from_slice(&rate_limit.try_borrow_data()?[0..8]);
+        if MintRateLimit::discriminator().eq(&discriminator) {
+            Self::update::<MintRateLimit>(rate_limit, period_limit, period_duration, reset)?;
+        } else if SlidingWindowRateLimit::discriminator().eq(&discriminator) {
+            Self::update::<SlidingWindowRateLimit>(rate_limit, period_limit, period_duration, reset)?;
+        } else if TokenBucketRateLimit::discriminator().eq(&discriminator) {
+            // token buckets are not configured by periods and reject updates
+            Self::update::<TokenBucketRateLimit>(rate_limit, period_limit, period_duration, reset)?;
+        } else if TransferCountRateLimit::discriminator().eq(&discriminator) {
+            Self::update::<TransferCountRateLimit>(rate_limit, period_limit, period_duration, reset)?;
+        } else if AuthorityRateLimit::discriminator().eq(&discriminator) {
+            Self::update::<AuthorityRateLimit>(rate_limit, period_limit, period_duration, reset)?;
+        } else {
+            return Err(RateLimitError::InvalidRateLimitAccount.into());
+        }
+
+        emit!(RateLimitUpdated {
+            authority: ctx.accounts.authority.key(),
+            rate_limit: rate_limit.key(),
+            period_limit,
+            period_duration,
+            reset,
+        });
+        Ok(())
+    }
+    fn update<T: AccountSerialize + AccountDeserialize + RateLimitConfig>(
+        rate_limit: &AccountInfo,
+        period_limit: u64,
+        period_duration: u64,
+        reset: bool,
+    ) -> Result<()> {
+        let mut state = T::try_deserialize(&mut &rate_limit.try_borrow_data()?[..])?;
+        state.update(period_limit, period_duration, reset)?;
+        state.try_serialize(&mut &mut rate_limit.try_borrow_mut_data()?[..])
+    }
+    fn validations(ctx: &Context<UpdateRateLimit>) -> Result<()> {
+        require!(
+            ctx.accounts.management.is_authorized(
+                ctx.accounts.authority.key(),
+                ctx.accounts.authority_role.as_deref(),
+                Role::RATE_LIMIT_MANAGER
+            ),
+            RateLimitError::Unauthorized
+        );
+        Ok(())
+    }
+}
@@ -1,9 +1,12 @@
 pub mod constants;
 pub mod error;
+pub mod events;
 pub mod instructions;
 pub mod state;
+pub mod utils;
 
 use anchor_lang::prelude::*;
+use state::{authority_rate_limit::LimitDirection, limiters::RateLimitType};
 
 pub use constants::*;
 pub use instructions::*;
@@ -20,6 +23,27 @@ pub mod rate_limits {
     pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
         Initialize::handler(ctx)
     }
+    pub fn propose_authority(ctx: Context<UpdateAuthority>, new_authority: Pubkey) -> Result<()> {
+        UpdateAuthority::propose_handler(ctx, new_authority)
+    }
+    pub fn cancel_authority_transfer(ctx: Context<UpdateAuthority>) -> Result<()> {
+        UpdateAuthority::cancel_handler(ctx)
+    }
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        AcceptAuthority::handler(ctx)
+    }
+    pub fn grant_role(ctx: Context<GrantRole>, holder: Pubkey, roles: u8) -> Result<()> {
+        GrantRole::handler(ctx, holder, roles)
+    }
+    pub fn revoke_role(ctx: Context<RevokeRole>, holder: Pubkey) -> Result<()> {
+        RevokeRole::handler(ctx, holder)
+    }
+    pub fn pause(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        UpdateMintConfig::pause_handler(ctx)
+    }
+    pub fn unpause(ctx: Context<UpdateMintConfig>) -> Result<()> {
+        UpdateMintConfig::unpause_handler(ctx)
+    }
     pub fn initialize_extra_account_meta_list(
         ctx: Context<InitializeExtraAccountMetaList>,
     ) -> Result<()> {
@@ -27,10 +51,56 @@ pub mod rate_limits {
     }
     pub fn create_mint_rate_limit(
         ctx: Context<CreateMintBasedRateLimit>,
+        index: u8,
+        rate_limit_type: RateLimitType,
         period_limit: u64,
         period_duration: u64,
     ) -> Result<()> {
-        CreateMintBasedRateLimit::handler(ctx, period_limit, period_duration)
+        CreateMintBasedRateLimit::handler(ctx, index, rate_limit_type, period_limit, period_duration)
+    }
+    pub fn create_authority_rate_limit(
+        ctx: Context<CreateAuthorityBasedRateLimit>,
+        period_limit: u64,
+        period_duration: u64,
+        direction: LimitDirection,
+    ) -> Result<()> {
+        CreateAuthorityBasedRateLimit::handler(ctx, period_limit, period_duration, direction)
+    }
+    pub fn initialize_authority_limit_entry(
+        ctx: Context<InitializeAuthorityLimitEntry>,
+        owner: Pubkey,
+        direction: LimitDirection,
+    ) -> Result<()> {
+        InitializeAuthorityLimitEntry::handler(ctx, owner, direction)
+    }
+    pub fn create_token_bucket_rate_limit(
+        ctx: Context<CreateTokenBucketRateLimit>,
+        capacity: u64,
+        refill_rate: u64,
+    ) -> Result<()> {
+        CreateTokenBucketRateLimit::handler(ctx, capacity, refill_rate)
+    }
+    pub fn create_transfer_count_rate_limit(
+        ctx: Context<CreateTransferCountRateLimit>,
+        limited_authority: Option<Pubkey>,
+        period_limit: u64,
+        period_duration: u64,
+    ) -> Result<()> {
+        CreateTransferCountRateLimit::handler(ctx, limited_authority, period_limit, period_duration)
+    }
+    pub fn update_rate_limit(
+        ctx: Context<UpdateRateLimit>,
+        period_limit: u64,
+        period_duration: u64,
+        reset: bool,
+    ) -> Result<()> {
+        UpdateRateLimit::handler(ctx, period_limit, period_duration, reset)
+    }
+    pub fn close_rate_limit(ctx: Context<CloseRateLimit>) -> Result<()> {
+        CloseRateLimit::handler(ctx)
+    }
+    pub fn close_limit_entry(ctx: Context<CloseLimitEntry>) -> Result<()> {
+        CloseLimitEntry::handler(ctx)
     }
     pub fn transfer_hook<'info>(ctx: Context<'_, '_, 'info, 'info,TransferHook<'info>>, amount: u64) -> Result<()> {
         TransferHook::handler(ctx, amount)
@@ -1,66 +1,194 @@
 use anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp};
+use spl_tlv_account_resolution::{account::ExtraAccountMeta, seeds::Seed};
 use crate::error::RateLimitError;
-use super::limiters::{LimiterEntry, RateLimitExt};
+use super::limiters::{RateLimitConfig, RateLimitType};
 
 
-/// Provides a rate limit implementation that rate limits transfers on a per-authority basis
+/// Denotes which owner of a transfer an authority based rate limit is keyed on
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LimitDirection {
+    /// Limits the amount sent by the owner of the source token account
+    Outbound,
+    /// Limits the amount received by the owner of the destination token account
+    Inbound,
+}
+
+impl LimitDirection {
+    /// Returns the seed prefix of the rate limit in this direction
+    pub fn rate_limit_seed(&self) -> &'static [u8] {
+        match self {
+            LimitDirection::Outbound => b"authority_based",
+            LimitDirection::Inbound => b"inbound",
+        }
+    }
+    /// Returns the seed prefix of the entries of the rate limit in this direction
+    pub fn entry_seed(&self) -> &'static [u8] {
+        match self {
+            LimitDirection::Outbound => b"authority_limit",
+            LimitDirection::Inbound => b"inbound_limit",
+        }
+    }
+    /// Returns the index of the token account whose owner is rate limited, in the accounts of the transfer hook
+    pub fn token_account_index(&self) -> u8 {
+        match self {
+            LimitDirection::Outbound => 0,
+            LimitDirection::Inbound => 2,
+        }
+    }
+    /// Returns the owner of the transfer which is rate limited in this direction
+    pub fn owner(&self, source_owner: Pubkey, destination_owner: Pubkey) -> Pubkey {
+        match self {
+            LimitDirection::Outbound => source_owner,
+            LimitDirection::Inbound => destination_owner,
+        }
+    }
+    pub fn rate_limit_type(&self) -> RateLimitType {
+        match self {
+            LimitDirection::Outbound => RateLimitType::AuthorityBased,
+            LimitDirection::Inbound => RateLimitType::Inbound,
+        }
+    }
+}
+
+/// Provides a rate limit implementation that rate limits transfers on a per-authority basis, keyed on
+/// either the owner of the source token account or the owner of the destination token account
+///
+/// The rate limit only holds the configuration shared by all authorities, the value transferred by each
+/// authority is tracked in its own `AuthorityLimitEntry` account
 #[account]
 #[derive(Debug)]
 pub struct AuthorityRateLimit {
     /// Maximum amount that can be transferred in a single period
     pub period_limit: u64,
-    /// The start time of the current period
+    /// The time the rate limit was created or last reset, entries with periods starting before it are reset
     pub current_period_start: UnixTimestamp,
     /// Duration of each period in seconds
     pub period_duration: u64,
     /// token mint the rate limit is for
     pub mint: Pubkey,
-    /// Vector of rate limit entries for different authorities
-    pub entries: Vec<LimiterEntry>,
+    /// Whether the amount sent or received by each owner is rate limited
+    pub direction: LimitDirection,
+    /// Bump seed of the address of the rate limit
+    pub bump: u8,
     #[cfg(test)]
     pub current_time: UnixTimestamp,
 }
 
+/// Tracks the value transferred by a single authority for the authority based rate limit of a mint
+#[account]
+#[derive(Debug)]
+pub struct AuthorityLimitEntry {
+    /// token mint the entry is for
+    pub mint: Pubkey,
+    /// The owner of the token accounts this entry corresponds to
+    pub owner: Pubkey,
+    /// The account which paid for the entry and receives its rent when it is closed
+    pub payer: Pubkey,
+    /// Direction of the rate limit the entry belongs to
+    pub direction: LimitDirection,
+    /// The start time of the current period of this entry
+    pub current_period_start: UnixTimestamp,
+    /// The amount of value this authority has sent or received in the current period
+    pub value_transferred: u64,
+    /// Bump seed of the address of the entry
+    pub bump: u8,
+}
+
 impl AuthorityRateLimit {
-    pub fn new(period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey) -> Result<Self> {
+    pub fn derive_pda(mint: Pubkey, direction: LimitDirection) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                direction.rate_limit_seed(),
+                mint.as_ref(),
+            ],
+            &crate::ID
+        )
+    }
+    /// Returns the address of the rate limit from its stored bump, without searching for the bump as
+    /// `derive_pda` does
+    pub fn pda(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                self.direction.rate_limit_seed(),
+                self.mint.as_ref(),
+                &[self.bump],
+            ],
+            &crate::ID
+        )
+        .map_err(|_| error!(RateLimitError::InvalidRateLimitAccount))
+    }
+    pub const fn space() -> usize {
+        8 //discriminator
+        + 8 // period_limit
+        + 8 // current_period_start
+        + 8 // period_duration
+        + 32 // mint
+        + 1 // direction
+        + 1 // bump
+    }
+    pub fn initialize(&mut self, period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey, direction: LimitDirection, bump: u8) -> Result<()> {
+        let rate_limit = Self::new(period_limit, period_duration, start_time, mint, direction, bump)?;
+        *self = rate_limit;
+
+        Ok(())
+    }
+    pub fn new(period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey, direction: LimitDirection, bump: u8) -> Result<Self> {
         require!(period_duration > 0, RateLimitError::InvalidPeriodConfig);
         #[cfg(test)]
         return Ok(Self {
-            entries: Vec::new(),
             period_limit,
             current_period_start: start_time,
             period_duration,
             mint,
+            direction,
+            bump,
             current_time: 0,
         });
 
         #[cfg(not(test))]
         return Ok(Self{
-            entries: Vec::new(),
             period_limit,
             current_period_start: start_time,
             mint,
-            period_duration,           
+            period_duration,
+            direction,
+            bump,
         });
     }
 
-    /// Initialize a new rate limit entry for an authority
-    pub fn init_limiter_entry(&mut self, authority: Pubkey) {
-        if self.limiter_entry(authority).is_none() {
-            self.entries.push(LimiterEntry {
-                authority,
-                value_transferred: 0,
-            });
+    /// Starts a new period for `entry` if its current period has ended, or the rate limit was reset
+    /// after it started
+    pub fn roll_over_entry(&self, entry: &mut AuthorityLimitEntry) {
+        let current_time = self.current_time();
+        if entry.current_period_start < self.current_period_start
+            || current_time >= entry.current_period_start.saturating_add(self.period_duration as i64)
+        {
+            entry.current_period_start = current_time;
+            entry.value_transferred = 0;
         }
     }
 
-    /// Returns Some(LimiterEntry) for the specific authority if it has a configured entry
-    /// 
-    /// Returns None for the specific authority if it has no configured entry
-    pub fn limiter_entry(&mut self, authority: Pubkey) -> Option<&mut LimiterEntry> {
-        self.entries
-            .iter_mut()
-            .find(|entry| entry.authority == authority)
+    /// Checks to see if the owner of `entry` can transfer `amount` of tokens, updating the entry if so
+    pub fn check_and_update_entry(&self, entry: &mut AuthorityLimitEntry, amount: u64) -> Result<()> {
+        // First check if the entry needs to roll over to a new period
+        self.roll_over_entry(entry);
+
+        // Check if the transfer would exceed the period limit
+        if entry.value_transferred.saturating_add(amount) > self.period_limit {
+            return err!(RateLimitError::RateLimitExceeded);
+        }
+
+        // Update the transferred amount
+        entry.value_transferred = entry.value_transferred.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Returns the amount the owner of `entry` can still transfer in its current period
+    pub fn remaining_for_entry(&self, entry: &AuthorityLimitEntry) -> u64 {
+        let mut entry = entry.clone();
+        self.roll_over_entry(&mut entry);
+        self.period_limit.saturating_sub(entry.value_transferred)
     }
 
     // Add method to update current time (for testing)
@@ -68,121 +196,188 @@ impl AuthorityRateLimit {
     pub fn set_current_time(&mut self, time: UnixTimestamp) {
         self.current_time = time;
     }
+    fn current_time(&self) -> UnixTimestamp {
+        #[cfg(test)]
+        return self.current_time;
+        #[cfg(not(test))]
+        return Clock::get().unwrap().unix_timestamp;
+    }
 }
 
-impl RateLimitExt for AuthorityRateLimit {
-    fn start_time(&self) -> UnixTimestamp {
-        self.current_period_start
+impl AuthorityLimitEntry {
+    pub fn derive_pda(mint: Pubkey, owner: Pubkey, direction: LimitDirection) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                direction.entry_seed(),
+                mint.as_ref(),
+                owner.as_ref(),
+            ],
+            &crate::ID
+        )
     }
-
-    fn end_time(&self) -> UnixTimestamp {
-        self.current_period_start.saturating_add(self.period_duration as i64)
+    /// Returns the address of the entry from its stored bump, without searching for the bump as
+    /// `derive_pda` does
+    pub fn pda(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                self.direction.entry_seed(),
+                self.mint.as_ref(),
+                self.owner.as_ref(),
+                &[self.bump],
+            ],
+            &crate::ID
+        )
+        .map_err(|_| error!(RateLimitError::InvalidRateLimitAccount))
     }
-
-    fn period_duration_seconds(&self) -> u64 {
-        self.period_duration
+    pub fn space() -> usize {
+        8 //discriminator
+        + 32 // mint
+        + 32 // owner
+        + 32 // payer
+        + 1 // direction
+        + 8 // current_period_start
+        + 8 // value_transferred
+        + 1 // bump
     }
-
-    fn roll_over(&mut self) {
-        #[cfg(test)]
-        let current_time = self.current_time;
-        #[cfg(not(test))]
-        let current_time = Clock::get().unwrap().unix_timestamp;
-
-
-        if current_time >= self.end_time() {
-            // Calculate how many periods have passed
-            let periods_elapsed = (current_time.checked_sub(self.current_period_start).unwrap() as u64)
-                .saturating_div(self.period_duration);
-            
-            // Update the period start time
-            self.current_period_start = self.current_period_start
-                .saturating_add((periods_elapsed * self.period_duration) as i64);
-            
-            // Reset all transfer amounts for the new period
-            for entry in self.entries.iter_mut() {
-                entry.value_transferred = 0;
-            }
+    /// Returns an entry for `owner` which has not transferred anything yet, its period starts at its first transfer
+    pub fn new(mint: Pubkey, owner: Pubkey, payer: Pubkey, direction: LimitDirection, bump: u8) -> Self {
+        Self {
+            mint,
+            owner,
+            payer,
+            direction,
+            current_period_start: 0,
+            value_transferred: 0,
+            bump,
         }
     }
+    /// Returns the account meta resolving the entry of the rate limited owner during a transfer, which is read
+    /// from the source or destination token account according to `direction`
+    pub fn extra_account_meta(direction: LimitDirection) -> Result<ExtraAccountMeta> {
+        Ok(ExtraAccountMeta::new_with_seeds(
+            &[
+                Seed::Literal {
+                    bytes: direction.entry_seed().to_vec(),
+                },
+                // index 1, mint
+                Seed::AccountKey { index: 1 },
+                // owner of the source token account at index 0, or destination token account at index 2
+                Seed::AccountData {
+                    account_index: direction.token_account_index(),
+                    data_index: 32,
+                    length: 32,
+                },
+            ],
+            false,
+            true,
+        )?)
+    }
+}
 
-    fn check_and_update(&mut self, authority: Option<Pubkey>, amount: u64) -> Result<()> {
-        let Some(authority) = authority else {
-            return Err(RateLimitError::InvalidCheckAndUpdate.into());
-        };
-
-        // First check if we need to roll over to a new period
-        self.roll_over();
-
-        let period_limit = self.period_limit;
-
-        // Get or create the limiter entry
-        let entry = if let Some(entry) = self.limiter_entry(authority) {
-            entry
-        } else {
-            self.init_limiter_entry(authority);
-            self.limiter_entry(authority).unwrap()
-        };
+impl RateLimitConfig for AuthorityRateLimit {
+    fn update(&mut self, period_limit: u64, period_duration: u64, reset: bool) -> Result<()> {
+        require!(period_duration > 0, RateLimitError::InvalidPeriodConfig);
+        self.period_limit = period_limit;
+        self.period_duration = period_duration;
 
-        // Check if the transfer would exceed the period limit
-        if entry.value_transferred.saturating_add(amount) > period_limit {
-            return err!(RateLimitError::RateLimitExceeded);
+        if reset {
+            // entries which started their period before now are reset on their next transfer
+            self.current_period_start = self.current_time();
         }
-
-        // Update the transferred amount
-        entry.value_transferred = entry.value_transferred.saturating_add(amount);
         Ok(())
     }
-
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn entry(owner: Pubkey) -> AuthorityLimitEntry {
+        AuthorityLimitEntry::new(Default::default(), owner, Default::default(), LimitDirection::Outbound, 0)
+    }
+
     #[test]
     fn test_rate_limit_basic() {
         let start_time = 1000;
-        let mut rate_limit = AuthorityRateLimit::new(100, 3600, start_time, Default::default()).unwrap(); // 100 tokens per hour
+        let mut rate_limit = AuthorityRateLimit::new(100, 3600, start_time, Default::default(), LimitDirection::Outbound, 0).unwrap(); // 100 tokens per hour
         rate_limit.set_current_time(start_time + 1);
 
-        let authority = Pubkey::new_unique();
+        let mut entry = entry(Pubkey::new_unique());
 
         // First transfer should work
-        assert!(rate_limit.check_and_update(Some(authority), 50).is_ok());
-        
+        assert!(rate_limit.check_and_update_entry(&mut entry, 50).is_ok());
+
         // Second transfer that would exceed limit should fail
-        assert!(rate_limit.check_and_update(Some(authority), 51).is_err());
-        
+        assert!(rate_limit.check_and_update_entry(&mut entry, 51).is_err());
+
         // Small transfer still within limits should work
-        assert!(rate_limit.check_and_update(Some(authority), 40).is_ok());
+        assert!(rate_limit.check_and_update_entry(&mut entry, 40).is_ok());
     }
 
     #[test]
     fn test_period_rollover() {
         let start_time = 1000;
-        let mut rate_limit = AuthorityRateLimit::new(100, 3600, start_time, Default::default()).unwrap();
+        let mut rate_limit = AuthorityRateLimit::new(100, 3600, start_time, Default::default(), LimitDirection::Outbound, 0).unwrap();
         rate_limit.set_current_time(start_time + 1);
-        let authority = Pubkey::new_unique();
+        let mut entry = entry(Pubkey::new_unique());
 
-        // Use up the limit
-        assert!(rate_limit.check_and_update(Some(authority), 100).is_ok());
-        
-        rate_limit.set_current_time(rate_limit.current_time+3600);
+        // the period of the entry starts at its first transfer
+        assert!(rate_limit.check_and_update_entry(&mut entry, 100).is_ok());
+        assert_eq!(entry.current_period_start, start_time + 1);
 
+        rate_limit.set_current_time(start_time + 3600);
+        assert!(rate_limit.check_and_update_entry(&mut entry, 1).is_err());
 
         // This should trigger a rollover and reset the limits
-        rate_limit.roll_over();
-        
-        // Should be able to transfer again
-        assert!(rate_limit.check_and_update(Some(authority), 100).is_ok());
+        rate_limit.set_current_time(start_time + 3601);
+        assert_eq!(rate_limit.remaining_for_entry(&entry), 100);
+        assert!(rate_limit.check_and_update_entry(&mut entry, 100).is_ok());
+        assert_eq!(entry.current_period_start, start_time + 3601);
+    }
 
+    #[test]
+    fn test_authorities_limited_independently() {
+        let start_time = 1000;
+        let mut rate_limit = AuthorityRateLimit::new(100, 3600, start_time, Default::default(), LimitDirection::Outbound, 0).unwrap();
+        rate_limit.set_current_time(start_time + 1);
+
+        let mut entry1 = entry(Pubkey::new_unique());
+        let mut entry2 = entry(Pubkey::new_unique());
 
-        rate_limit.set_current_time(rate_limit.current_time+9600);
+        // Use up the limit for the first authority
+        assert!(rate_limit.check_and_update_entry(&mut entry1, 100).is_ok());
+        assert!(rate_limit.check_and_update_entry(&mut entry1, 1).is_err());
 
-        rate_limit.roll_over();;
+        assert_eq!(rate_limit.remaining_for_entry(&entry1), 0);
 
-        assert_eq!(rate_limit.current_period_start, 11800);
+        // Second authority has its own allowance
+        assert_eq!(rate_limit.remaining_for_entry(&entry2), 100);
+        assert!(rate_limit.check_and_update_entry(&mut entry2, 60).is_ok());
+        assert_eq!(rate_limit.remaining_for_entry(&entry2), 40);
 
+        // resetting the rate limit resets every entry on its next transfer
+        rate_limit.set_current_time(start_time + 10);
+        rate_limit.update(100, 3600, true).unwrap();
+        assert_eq!(rate_limit.remaining_for_entry(&entry1), 100);
+        assert!(rate_limit.check_and_update_entry(&mut entry1, 100).is_ok());
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_directions_use_separate_accounts() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        assert_ne!(
+            AuthorityRateLimit::derive_pda(mint, LimitDirection::Outbound).0,
+            AuthorityRateLimit::derive_pda(mint, LimitDirection::Inbound).0
+        );
+        assert_ne!(
+            AuthorityLimitEntry::derive_pda(mint, owner, LimitDirection::Outbound).0,
+            AuthorityLimitEntry::derive_pda(mint, owner, LimitDirection::Inbound).0
+        );
+
+        let source_owner = Pubkey::new_unique();
+        let destination_owner = Pubkey::new_unique();
+        assert_eq!(LimitDirection::Outbound.owner(source_owner, destination_owner), source_owner);
+        assert_eq!(LimitDirection::Inbound.owner(source_owner, destination_owner), destination_owner);
+    }
+}
@@ -22,6 +22,36 @@ pub trait RateLimitExt {
     /// 
     /// If the authority is rate limited, returns an error.
     fn check_and_update(&mut self, authority: Option<Pubkey>, amount: u64) -> Result<()>;
+    /// Returns the amount `authority` can still transfer in the current period
+    fn remaining(&self, authority: Option<Pubkey>) -> u64;
+}
+
+/// Trait that defines how the configuration of a rate limit is changed through `update_rate_limit`.
+///
+/// Kept separate from `RateLimitExt` so that rate limits which do not check transfers on their own, such as
+/// authority based rate limits whose usage is tracked in entries, can still be updated
+pub trait RateLimitConfig {
+    /// Updates the limit and duration of the rate limit.
+    ///
+    /// If `reset` is true a new period is started at the current time with no value transferred,
+    /// otherwise the current period and the value transferred in it are preserved
+    fn update(&mut self, period_limit: u64, period_duration: u64, reset: bool) -> Result<()>;
+}
+
+/// Validates that the rate limit at `key`, which stores `rate_limit_mint`, belongs to `mint` and is
+/// the PDA `expected_key` derived for it, rejecting rate limits of other mints or accounts which merely
+/// have the discriminator of a rate limit
+pub fn validate_rate_limit_account(
+    key: &Pubkey,
+    rate_limit_mint: &Pubkey,
+    mint: &Pubkey,
+    expected_key: Pubkey,
+) -> Result<()> {
+    require!(
+        rate_limit_mint.eq(mint) && key.eq(&expected_key),
+        RateLimitError::InvalidRateLimitAccount
+    );
+    Ok(())
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
@@ -32,6 +62,11 @@ pub struct LimiterEntry {
     pub value_transferred: u64,
 }
 
+impl LimiterEntry {
+    pub const SIZE: usize = 32 // authority
+        + 8; // value_transferred
+}
+
 
 /// Denotes the possible types of rate limits which can be created
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
@@ -39,6 +74,9 @@ pub struct LimiterEntry {
 pub enum RateLimitType {
     AuthorityBased,
     MintBased,
+    SlidingWindow,
+    TransferCount,
+    Inbound,
 }
 
 impl TryFrom<u8> for RateLimitType {
@@ -47,7 +85,68 @@ impl TryFrom<u8> for RateLimitType {
         match value {
             0 => Ok(RateLimitType::AuthorityBased),
             1 => Ok(RateLimitType::MintBased),
+            2 => Ok(RateLimitType::SlidingWindow),
+            3 => Ok(RateLimitType::TransferCount),
+            4 => Ok(RateLimitType::Inbound),
             _ => Err(RateLimitError::InvalidRateLimitType)
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mint_rate_limit::MintRateLimit;
+
+    fn error_code(result: Result<()>) -> u32 {
+        match result.unwrap_err() {
+            Error::AnchorError(error) => error.error_code_number,
+            error => panic!("unexpected error {error:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_rate_limit_account() {
+        let mint = Pubkey::new_unique();
+        let (rate_limit, bump) = MintRateLimit::derive_pda(mint, 0);
+        let state = MintRateLimit::new(100, 3600, 0, mint, 0, bump).unwrap();
+        assert_eq!(state.pda().unwrap(), rate_limit);
+        assert!(validate_rate_limit_account(&rate_limit, &state.mint, &mint, state.pda().unwrap()).is_ok());
+
+        let invalid_rate_limit_account = u32::from(RateLimitError::InvalidRateLimitAccount);
+
+        // rate limit belonging to another mint
+        let other_mint = Pubkey::new_unique();
+        let (other_rate_limit, _) = MintRateLimit::derive_pda(other_mint, 0);
+        assert_eq!(
+            error_code(validate_rate_limit_account(
+                &other_rate_limit,
+                &other_mint,
+                &mint,
+                MintRateLimit::derive_pda(other_mint, 0).0,
+            )),
+            invalid_rate_limit_account
+        );
+
+        // account storing the mint which is not the PDA of the rate limit
+        assert_eq!(
+            error_code(validate_rate_limit_account(
+                &Pubkey::new_unique(),
+                &mint,
+                &mint,
+                rate_limit,
+            )),
+            invalid_rate_limit_account
+        );
+
+        // rate limit storing another bump than the one of its address
+        let mut wrong_bump = state.clone();
+        wrong_bump.bump = bump.wrapping_sub(1);
+        if let Ok(address) = wrong_bump.pda() {
+            assert_eq!(
+                error_code(validate_rate_limit_account(&rate_limit, &mint, &mint, address)),
+                invalid_rate_limit_account
+            );
+        }
+    }
+}
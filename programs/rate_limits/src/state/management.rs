@@ -1,19 +1,25 @@
-use anchor_lang::prelude::*;
+use {crate::role::Role, anchor_lang::prelude::*};
 
 #[account]
 pub struct Management {
     pub authority: Pubkey,
+    /// Authority proposed through `propose_authority`, which must accept the transfer
+    pub pending_authority: Option<Pubkey>,
 }
 
 impl Management {
     pub fn space() -> usize {
         8 + // discriminator
-        32 // authority
+        32 + // authority
+        1 + 32 // pending_authority
     }
     pub fn derive_pda() -> (Pubkey, u8) {
         Pubkey::find_program_address(&[b"management"], &crate::ID)
     }
-    pub fn is_authorized(&self, authority: Pubkey) -> bool {
+    /// Returns whether `authority` may perform actions requiring `required_role`, either as the
+    /// management authority or as the holder of `role`
+    pub fn is_authorized(&self, authority: Pubkey, role: Option<&Role>, required_role: u8) -> bool {
         self.authority == authority
+            || role.is_some_and(|role| role.holder == authority && role.has_role(required_role))
     }
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+/// Per-mint configuration, read by the transfer hook on every transfer
+#[account]
+pub struct MintConfig {
+    /// token mint the configuration is for
+    pub mint: Pubkey,
+    /// Whether transfers of the mint are paused
+    pub paused: bool,
+}
+
+impl MintConfig {
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // mint
+        1 // paused
+    }
+    pub fn derive_pda(mint: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"mint_config", mint.as_ref()], &crate::ID)
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp};
 use crate::error::RateLimitError;
-use super::limiters::{LimiterEntry, RateLimitExt};
+use super::limiters::{RateLimitConfig, RateLimitExt};
 
 
 /// Provides a rate limit implementation that rate limits transfers on a per-mint basis
@@ -17,20 +17,39 @@ pub struct MintRateLimit {
     pub mint: Pubkey,
     /// The value that has been transferred in the current period
     pub value_transferred: u64,
+    /// Index of the rate limit, allowing several windows to be configured for the same mint
+    pub index: u8,
+    /// Bump seed of the address of the rate limit
+    pub bump: u8,
     #[cfg(test)]
     pub current_time: UnixTimestamp,
 }
 
 impl MintRateLimit {
-    pub fn derive_pda(mint: Pubkey) -> (Pubkey, u8) {
+    pub fn derive_pda(mint: Pubkey, index: u8) -> (Pubkey, u8) {
         Pubkey::find_program_address(
             &[
                 b"mint_based",
                 mint.as_ref(),
+                &[index],
             ],
             &crate::ID
         )
     }
+    /// Returns the address of the rate limit from its stored bump, without searching for the bump as
+    /// `derive_pda` does
+    pub fn pda(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                b"mint_based",
+                self.mint.as_ref(),
+                &[self.index],
+                &[self.bump],
+            ],
+            &crate::ID
+        )
+        .map_err(|_| error!(RateLimitError::InvalidRateLimitAccount))
+    }
     pub fn space() -> usize {
         8 //discriminator
         + 8 // period_limit
@@ -38,9 +57,11 @@ impl MintRateLimit {
         + 8 // period_duration
         + 32 // mint
         + 8 // value_transferred
+        + 1 // index
+        + 1 // bump
     }
-    pub fn initialize(&mut self, period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey) -> Result<()> {
-        let rate_limit = Self::new(period_limit, period_duration, start_time, mint)?;
+    pub fn initialize(&mut self, period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey, index: u8, bump: u8) -> Result<()> {
+        let rate_limit = Self::new(period_limit, period_duration, start_time, mint, index, bump)?;
         *self = rate_limit;
 
         Ok(())
@@ -50,7 +71,7 @@ impl MintRateLimit {
     pub fn set_current_time(&mut self, time: UnixTimestamp) {
         self.current_time = time;
     }
-    fn new(period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey) -> Result<Self> {
+    pub fn new(period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey, index: u8, bump: u8) -> Result<Self> {
         require!(period_duration > 0, RateLimitError::InvalidPeriodConfig);
         #[cfg(test)]
         return Ok(Self {
@@ -60,6 +81,8 @@ impl MintRateLimit {
             mint,
             current_time: 0,
             value_transferred: 0,
+            index,
+            bump,
         });
 
         #[cfg(not(test))]
@@ -69,6 +92,8 @@ impl MintRateLimit {
             mint,
             period_duration,
             value_transferred: 0,
+            index,
+            bump,
         });
     }
 }
@@ -124,6 +149,29 @@ impl RateLimitExt for MintRateLimit {
         self.value_transferred = new_value_transferred;
         Ok(())
     }
+
+    fn remaining(&self, _authority: Option<Pubkey>) -> u64 {
+        self.period_limit.saturating_sub(self.value_transferred)
+    }
+}
+
+impl RateLimitConfig for MintRateLimit {
+    fn update(&mut self, period_limit: u64, period_duration: u64, reset: bool) -> Result<()> {
+        require!(period_duration > 0, RateLimitError::InvalidPeriodConfig);
+        self.period_limit = period_limit;
+        self.period_duration = period_duration;
+
+        if reset {
+            #[cfg(test)]
+            let current_time = self.current_time;
+            #[cfg(not(test))]
+            let current_time = Clock::get()?.unix_timestamp;
+
+            self.current_period_start = current_time;
+            self.value_transferred = 0;
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -139,13 +187,13 @@ mod tests {
             current_period_start: 0,
             mint: Default::default(),
             value_transferred: 0,
+            index: 0,
+            bump: 0,
             current_time: 0,
         };
-        rate_limit.initialize(100, 3600, start_time, Default::default()).unwrap(); // 100 tokens per hour
+        rate_limit.initialize(100, 3600, start_time, Default::default(), 0, 0).unwrap(); // 100 tokens per hour
         rate_limit.set_current_time(start_time + 1);
 
-        let authority = Pubkey::new_unique();
-
         // First transfer should work
         assert!(rate_limit.check_and_update(None, 50).is_ok());
         
@@ -165,9 +213,11 @@ mod tests {
             current_period_start: 0,
             mint: Default::default(),
             value_transferred: 0,
+            index: 0,
+            bump: 0,
             current_time: 0,
         };
-        rate_limit.initialize(100, 3600, start_time, Default::default()).unwrap(); // 100 tokens per hour
+        rate_limit.initialize(100, 3600, start_time, Default::default(), 0, 0).unwrap(); // 100 tokens per hour
         rate_limit.set_current_time(start_time + 1);
 
         // Use up the limit
@@ -185,9 +235,32 @@ mod tests {
 
         rate_limit.set_current_time(rate_limit.current_time+9600);
 
-        rate_limit.roll_over();;
+        rate_limit.roll_over();
 
         assert_eq!(rate_limit.current_period_start, 11800);
 
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_update_preserves_or_resets_period() {
+        let start_time = 1000;
+        let mut rate_limit = MintRateLimit::new(100, 3600, start_time, Default::default(), 0, 0).unwrap();
+        rate_limit.set_current_time(start_time + 1);
+        assert!(rate_limit.check_and_update(None, 80).is_ok());
+
+        // raising the limit preserves the value transferred in the current period
+        rate_limit.update(150, 7200, false).unwrap();
+        assert_eq!(rate_limit.current_period_start, start_time);
+        assert_eq!(rate_limit.end_time(), start_time + 7200);
+        assert_eq!(rate_limit.remaining(None), 70);
+        assert!(rate_limit.check_and_update(None, 71).is_err());
+
+        // resetting starts a new period at the current time
+        rate_limit.set_current_time(start_time + 10);
+        rate_limit.update(50, 60, true).unwrap();
+        assert_eq!(rate_limit.current_period_start, start_time + 10);
+        assert_eq!(rate_limit.remaining(None), 50);
+
+        assert!(rate_limit.update(50, 0, false).is_err());
+    }
+}
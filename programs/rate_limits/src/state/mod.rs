@@ -1,4 +1,9 @@
 pub mod authority_rate_limit;
 pub mod limiters;
 pub mod management;
-pub mod mint_rate_limit;
\ No newline at end of file
+pub mod mint_config;
+pub mod mint_rate_limit;
+pub mod role;
+pub mod sliding_window_rate_limit;
+pub mod token_bucket_rate_limit;
+pub mod transfer_count_rate_limit;
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+/// Roles granted to an address, allowing the administration of the deployment to be
+/// split between several addresses.
+///
+/// The management authority implicitly holds every role.
+#[account]
+pub struct Role {
+    /// The address the roles are granted to
+    pub holder: Pubkey,
+    /// Bitmask of the granted roles
+    pub roles: u8,
+}
+
+impl Role {
+    /// Grants every role, including granting and revoking roles
+    pub const ADMIN: u8 = 1 << 0;
+    /// Allows creating rate limits
+    pub const RATE_LIMIT_MANAGER: u8 = 1 << 1;
+    /// Allows pausing and unpausing transfers of mints
+    pub const PAUSER: u8 = 1 << 2;
+    /// Every role which can be granted
+    pub const ALL: u8 = Self::ADMIN | Self::RATE_LIMIT_MANAGER | Self::PAUSER;
+
+    pub fn space() -> usize {
+        8 + // discriminator
+        32 + // holder
+        1 // roles
+    }
+    pub fn derive_pda(holder: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[b"role", holder.as_ref()], &crate::ID)
+    }
+    /// Returns whether `roles` is a non-empty set of known roles
+    pub fn is_valid(roles: u8) -> bool {
+        roles != 0 && roles & !Self::ALL == 0
+    }
+    /// Returns whether the holder has `role`, admins hold every role
+    pub fn has_role(&self, role: u8) -> bool {
+        self.roles & (Self::ADMIN | role) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_role() {
+        let mut role = Role {
+            holder: Pubkey::new_unique(),
+            roles: Role::RATE_LIMIT_MANAGER,
+        };
+        assert!(role.has_role(Role::RATE_LIMIT_MANAGER));
+        assert!(!role.has_role(Role::ADMIN));
+        assert!(!role.has_role(Role::PAUSER));
+
+        role.roles = Role::ADMIN;
+        assert!(role.has_role(Role::RATE_LIMIT_MANAGER));
+        assert!(role.has_role(Role::PAUSER));
+
+        assert!(Role::is_valid(Role::RATE_LIMIT_MANAGER | Role::PAUSER));
+        assert!(!Role::is_valid(0));
+        assert!(!Role::is_valid(1 << 7));
+    }
+}
@@ -0,0 +1,230 @@
+use anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp};
+use crate::error::RateLimitError;
+use super::limiters::{RateLimitConfig, RateLimitExt};
+
+
+/// Provides a rate limit implementation that rate limits transfers on a per-mint basis over a sliding window.
+///
+/// The window is approximated by weighting the value transferred in the previous period by how much of
+/// the previous period still overlaps the window, which prevents transferring twice the limit across a
+/// period boundary.
+#[account]
+#[derive(Debug)]
+pub struct SlidingWindowRateLimit {
+    /// Maximum amount that can be transferred in any window of `period_duration` seconds
+    pub period_limit: u64,
+    /// The start time of the current period
+    pub current_period_start: UnixTimestamp,
+    /// Duration of each period in seconds
+    pub period_duration: u64,
+    /// token mint the rate limit is for
+    pub mint: Pubkey,
+    /// The value that was transferred in the previous period
+    pub previous_value_transferred: u64,
+    /// The value that has been transferred in the current period
+    pub value_transferred: u64,
+    /// Index of the rate limit, shared with mint based rate limits of the same mint
+    pub index: u8,
+    /// Bump seed of the address of the rate limit
+    pub bump: u8,
+    #[cfg(test)]
+    pub current_time: UnixTimestamp,
+}
+
+impl SlidingWindowRateLimit {
+    pub fn derive_pda(mint: Pubkey, index: u8) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"mint_based",
+                mint.as_ref(),
+                &[index],
+            ],
+            &crate::ID
+        )
+    }
+    /// Returns the address of the rate limit from its stored bump, without searching for the bump as
+    /// `derive_pda` does
+    pub fn pda(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                b"mint_based",
+                self.mint.as_ref(),
+                &[self.index],
+                &[self.bump],
+            ],
+            &crate::ID
+        )
+        .map_err(|_| error!(RateLimitError::InvalidRateLimitAccount))
+    }
+    pub fn space() -> usize {
+        8 //discriminator
+        + 8 // period_limit
+        + 8 // current_period_start
+        + 8 // period_duration
+        + 32 // mint
+        + 8 // previous_value_transferred
+        + 8 // value_transferred
+        + 1 // index
+        + 1 // bump
+    }
+    pub fn new(period_limit: u64, period_duration: u64, start_time: UnixTimestamp, mint: Pubkey, index: u8, bump: u8) -> Result<Self> {
+        require!(period_duration > 0, RateLimitError::InvalidPeriodConfig);
+        #[cfg(test)]
+        return Ok(Self {
+            period_limit,
+            current_period_start: start_time,
+            period_duration,
+            mint,
+            previous_value_transferred: 0,
+            value_transferred: 0,
+            index,
+            current_time: 0,
+            bump,
+        });
+
+        #[cfg(not(test))]
+        return Ok(Self{
+            period_limit,
+            current_period_start: start_time,
+            period_duration,
+            mint,
+            previous_value_transferred: 0,
+            value_transferred: 0,
+            index,
+            bump,
+        });
+    }
+    // Add method to update current time (for testing)
+    #[cfg(test)]
+    pub fn set_current_time(&mut self, time: UnixTimestamp) {
+        self.current_time = time;
+    }
+    fn current_time(&self) -> UnixTimestamp {
+        #[cfg(test)]
+        return self.current_time;
+        #[cfg(not(test))]
+        return Clock::get().unwrap().unix_timestamp;
+    }
+    /// Returns the value transferred within the sliding window ending at the current time
+    fn value_in_window(&self) -> u64 {
+        let elapsed = (self.current_time().saturating_sub(self.current_period_start).max(0) as u64)
+            .min(self.period_duration);
+        // portion of the previous period which still overlaps the window
+        let previous_weight = self.period_duration - elapsed;
+        let weighted_previous = (self.previous_value_transferred as u128 * previous_weight as u128)
+            .div_ceil(self.period_duration as u128) as u64;
+        weighted_previous.saturating_add(self.value_transferred)
+    }
+}
+
+impl RateLimitExt for SlidingWindowRateLimit {
+    fn start_time(&self) -> UnixTimestamp {
+        self.current_period_start
+    }
+
+    fn end_time(&self) -> UnixTimestamp {
+        self.current_period_start.saturating_add(self.period_duration as i64)
+    }
+
+    fn period_duration_seconds(&self) -> u64 {
+        self.period_duration
+    }
+
+    fn roll_over(&mut self) {
+        let current_time = self.current_time();
+
+        if current_time >= self.end_time() {
+            // Calculate how many periods have passed
+            let periods_elapsed = (current_time.checked_sub(self.current_period_start).unwrap() as u64)
+                .saturating_div(self.period_duration);
+
+            // Update the period start time
+            self.current_period_start = self.current_period_start
+                .saturating_add((periods_elapsed * self.period_duration) as i64);
+
+            // the current period only overlaps the window if it immediately precedes the new period
+            self.previous_value_transferred = if periods_elapsed == 1 {
+                self.value_transferred
+            } else {
+                0
+            };
+            self.value_transferred = 0;
+        }
+    }
+
+    fn check_and_update(&mut self, _authority: Option<Pubkey>, amount: u64) -> Result<()> {
+        // First check if we need to roll over to a new period
+        self.roll_over();
+
+        // Check if the transfer would exceed the limit of the window
+        if self.value_in_window().saturating_add(amount) > self.period_limit {
+            return err!(RateLimitError::RateLimitExceeded);
+        }
+
+        self.value_transferred = self.value_transferred.saturating_add(amount);
+        Ok(())
+    }
+
+    fn remaining(&self, _authority: Option<Pubkey>) -> u64 {
+        self.period_limit.saturating_sub(self.value_in_window())
+    }
+}
+
+impl RateLimitConfig for SlidingWindowRateLimit {
+    fn update(&mut self, period_limit: u64, period_duration: u64, reset: bool) -> Result<()> {
+        require!(period_duration > 0, RateLimitError::InvalidPeriodConfig);
+        self.period_limit = period_limit;
+        self.period_duration = period_duration;
+
+        if reset {
+            self.current_period_start = self.current_time();
+            self.previous_value_transferred = 0;
+            self.value_transferred = 0;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_basic() {
+        let start_time = 1000;
+        let mut rate_limit = SlidingWindowRateLimit::new(100, 3600, start_time, Default::default(), 0, 0).unwrap();
+        rate_limit.set_current_time(start_time + 1);
+
+        assert!(rate_limit.check_and_update(None, 50).is_ok());
+        assert!(rate_limit.check_and_update(None, 51).is_err());
+        assert!(rate_limit.check_and_update(None, 40).is_ok());
+        assert_eq!(rate_limit.remaining(None), 10);
+    }
+
+    #[test]
+    fn test_no_burst_across_period_boundary() {
+        let start_time = 1000;
+        let mut rate_limit = SlidingWindowRateLimit::new(100, 3600, start_time, Default::default(), 0, 0).unwrap();
+
+        // use up the limit at the very end of the period
+        rate_limit.set_current_time(start_time + 3599);
+        assert!(rate_limit.check_and_update(None, 100).is_ok());
+
+        // the previous period still fully overlaps the window right after the boundary
+        rate_limit.set_current_time(start_time + 3600);
+        assert!(rate_limit.check_and_update(None, 1).is_err());
+        assert_eq!(rate_limit.current_period_start, start_time + 3600);
+
+        // half way through the period, half of the previous period has left the window
+        rate_limit.set_current_time(start_time + 3600 + 1800);
+        assert_eq!(rate_limit.remaining(None), 50);
+        assert!(rate_limit.check_and_update(None, 51).is_err());
+        assert!(rate_limit.check_and_update(None, 50).is_ok());
+
+        // skipping a whole period clears the window
+        rate_limit.set_current_time(start_time + 3 * 3600);
+        rate_limit.roll_over();
+        assert_eq!(rate_limit.previous_value_transferred, 0);
+        assert_eq!(rate_limit.remaining(None), 100);
+    }
+}
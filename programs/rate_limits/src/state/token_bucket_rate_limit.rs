@@ -0,0 +1,192 @@
+use anchor_lang::{prelude::*, solana_program::clock::UnixTimestamp};
+use crate::error::RateLimitError;
+use super::limiters::{RateLimitConfig, RateLimitExt};
+
+
+/// Provides a token bucket rate limit implementation that rate limits transfers on a per-mint basis.
+///
+/// The bucket holds up to `capacity` tokens and is refilled continuously at `refill_rate` tokens per second,
+/// allowing short bursts of up to `capacity` while capping the sustained rate of transfers.
+#[account]
+#[derive(Debug)]
+pub struct TokenBucketRateLimit {
+    /// Maximum amount of tokens the bucket can hold, which is the largest burst that can be transferred
+    pub capacity: u64,
+    /// Amount of tokens added to the bucket every second
+    pub refill_rate: u64,
+    /// Amount of tokens currently in the bucket as of `last_update`
+    pub tokens: u64,
+    /// The time the bucket was last refilled
+    pub last_update: UnixTimestamp,
+    /// token mint the rate limit is for
+    pub mint: Pubkey,
+    /// Bump seed of the address of the rate limit
+    pub bump: u8,
+    #[cfg(test)]
+    pub current_time: UnixTimestamp,
+}
+
+impl TokenBucketRateLimit {
+    pub fn derive_pda(mint: Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"token_bucket",
+                mint.as_ref(),
+            ],
+            &crate::ID
+        )
+    }
+    /// Returns the address of the rate limit from its stored bump, without searching for the bump as
+    /// `derive_pda` does
+    pub fn pda(&self) -> Result<Pubkey> {
+        Pubkey::create_program_address(
+            &[
+                b"token_bucket",
+                self.mint.as_ref(),
+                &[self.bump],
+            ],
+            &crate::ID
+        )
+        .map_err(|_| error!(RateLimitError::InvalidRateLimitAccount))
+    }
+    pub fn space() -> usize {
+        8 //discriminator
+        + 8 // capacity
+        + 8 // refill_rate
+        + 8 // tokens
+        + 8 // last_update
+        + 32 // mint
+        + 1 // bump
+    }
+    pub fn initialize(&mut self, capacity: u64, refill_rate: u64, start_time: UnixTimestamp, mint: Pubkey, bump: u8) -> Result<()> {
+        let rate_limit = Self::new(capacity, refill_rate, start_time, mint, bump)?;
+        *self = rate_limit;
+
+        Ok(())
+    }
+    /// Creates a full token bucket
+    pub fn new(capacity: u64, refill_rate: u64, start_time: UnixTimestamp, mint: Pubkey, bump: u8) -> Result<Self> {
+        require!(capacity > 0 && refill_rate > 0, RateLimitError::InvalidPeriodConfig);
+        #[cfg(test)]
+        return Ok(Self {
+            capacity,
+            refill_rate,
+            tokens: capacity,
+            last_update: start_time,
+            mint,
+            current_time: 0,
+            bump,
+        });
+
+        #[cfg(not(test))]
+        return Ok(Self{
+            capacity,
+            refill_rate,
+            tokens: capacity,
+            last_update: start_time,
+            mint,
+            bump,
+        });
+    }
+    // Add method to update current time (for testing)
+    #[cfg(test)]
+    pub fn set_current_time(&mut self, time: UnixTimestamp) {
+        self.current_time = time;
+    }
+    fn current_time(&self) -> UnixTimestamp {
+        #[cfg(test)]
+        return self.current_time;
+        #[cfg(not(test))]
+        return Clock::get().unwrap().unix_timestamp;
+    }
+    /// Returns the amount of tokens in the bucket at the current time
+    fn refilled_tokens(&self) -> u64 {
+        let elapsed = self.current_time().saturating_sub(self.last_update).max(0) as u64;
+        self.tokens
+            .saturating_add(elapsed.saturating_mul(self.refill_rate))
+            .min(self.capacity)
+    }
+}
+
+impl RateLimitExt for TokenBucketRateLimit {
+    fn start_time(&self) -> UnixTimestamp {
+        self.last_update
+    }
+
+    /// Returns the time at which the bucket will be full again
+    fn end_time(&self) -> UnixTimestamp {
+        let missing = self.capacity.saturating_sub(self.tokens);
+        self.last_update.saturating_add(missing.div_ceil(self.refill_rate) as i64)
+    }
+
+    /// Returns the time it takes to refill an empty bucket
+    fn period_duration_seconds(&self) -> u64 {
+        self.capacity.div_ceil(self.refill_rate)
+    }
+
+    fn roll_over(&mut self) {
+        let current_time = self.current_time();
+        if current_time > self.last_update {
+            self.tokens = self.refilled_tokens();
+            self.last_update = current_time;
+        }
+    }
+
+    fn check_and_update(&mut self, _authority: Option<Pubkey>, amount: u64) -> Result<()> {
+        // First refill the bucket for the time elapsed since the last transfer
+        self.roll_over();
+
+        // Check if the transfer would take more tokens than the bucket holds
+        if amount > self.tokens {
+            return err!(RateLimitError::RateLimitExceeded);
+        }
+
+        self.tokens -= amount;
+        Ok(())
+    }
+
+    fn remaining(&self, _authority: Option<Pubkey>) -> u64 {
+        self.refilled_tokens()
+    }
+}
+
+impl RateLimitConfig for TokenBucketRateLimit {
+    /// Token buckets are configured by capacity and refill rate rather than periods, and can not be
+    /// updated through `update_rate_limit`
+    fn update(&mut self, _period_limit: u64, _period_duration: u64, _reset: bool) -> Result<()> {
+        err!(RateLimitError::InvalidRateLimitType)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_burst_and_refill() {
+        let start_time = 1000;
+        // bursts of up to 100 tokens, refilled at 2 tokens per second
+        let mut rate_limit = TokenBucketRateLimit::new(100, 2, start_time, Default::default(), 0).unwrap();
+        rate_limit.set_current_time(start_time);
+
+        // the full capacity can be transferred at once
+        assert!(rate_limit.check_and_update(None, 100).is_ok());
+        assert!(rate_limit.check_and_update(None, 1).is_err());
+        assert_eq!(rate_limit.end_time(), start_time + 50);
+
+        // tokens are refilled continuously
+        rate_limit.set_current_time(start_time + 10);
+        assert_eq!(rate_limit.remaining(None), 20);
+        assert!(rate_limit.check_and_update(None, 21).is_err());
+        assert!(rate_limit.check_and_update(None, 20).is_ok());
+        assert_eq!(rate_limit.last_update, start_time + 10);
+
+        // the bucket never holds more than its capacity
+        rate_limit.set_current_time(start_time + 1000);
+        assert_eq!(rate_limit.remaining(None), 100);
+        assert!(rate_limit.check_and_update(None, 101).is_err());
+
+        assert!(rate_limit.update(100, 60, true).is_err());
+        assert!(TokenBucketRateLimit::new(100, 0, start_time, Default::default(), 0).is_err());
+    }
+}